@@ -0,0 +1,65 @@
+//! HTTP API surface: wires [`GeoVANError`](crate::error::GeoVANError) into
+//! `axum` responses as RFC 7807 `application/problem+json` bodies, so
+//! handlers can return a `Result<_, GeoVANError>` and get a consistent error
+//! contract for free.
+
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use crate::error::GeoVANError;
+use crate::metrics::errors as error_metrics;
+
+impl IntoResponse for GeoVANError {
+    fn into_response(self) -> Response {
+        error_metrics::record(&self);
+        let problem = self.problem_details();
+        let status = StatusCode::from_u16(problem.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = match serde_json::to_vec(&problem) {
+            Ok(bytes) => bytes,
+            Err(_) => b"{\"title\":\"Internal Error\",\"status\":500}".to_vec(),
+        };
+
+        (status, [(header::CONTENT_TYPE, "application/problem+json")], body).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ValidationError;
+
+    #[tokio::test]
+    async fn test_into_response_sets_problem_json_content_type() {
+        let error = GeoVANError::Validation(ValidationError::RequiredFieldMissing("email".to_string()));
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_into_response_records_error_metric() {
+        use crate::metrics::REGISTRY;
+
+        let before = REGISTRY
+            .gather()
+            .into_iter()
+            .find(|mf| mf.get_name() == "geovan_errors_total")
+            .map(|mf| mf.get_metric().iter().map(|m| m.get_counter().get_value()).sum::<f64>())
+            .unwrap_or(0.0);
+
+        let error = GeoVANError::Validation(ValidationError::RequiredFieldMissing("email".to_string()));
+        let _ = error.into_response();
+
+        let after = REGISTRY
+            .gather()
+            .into_iter()
+            .find(|mf| mf.get_name() == "geovan_errors_total")
+            .map(|mf| mf.get_metric().iter().map(|m| m.get_counter().get_value()).sum::<f64>())
+            .unwrap_or(0.0);
+
+        assert_eq!(after, before + 1.0);
+    }
+}
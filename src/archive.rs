@@ -0,0 +1,399 @@
+//! Compressed, append-only telemetry archive for offline analysis.
+//!
+//! Unlike [`crate::log`], which is tuned for durability across software
+//! versions, this module is tuned for size: [`VehiclePosition`] records are
+//! batched into time-bucketed segments, encoded with [`crate::codec`]'s
+//! fixed wire layout, and DEFLATE-compressed as a unit, since compression
+//! works far better across a batch of similar records than on each one
+//! alone. A small [`SegmentIndexEntry`] per segment records the byte
+//! offset, the vehicles and time range it covers, and the achieved
+//! compression ratio, so [`ArchiveReader::query`] only has to decompress
+//! the segments that could possibly contain a match. Segments carry no
+//! self-describing length, so the index is the only record of segment
+//! boundaries; persist it alongside the data file with
+//! [`write_index`]/[`read_index`] so the archive stays queryable after a
+//! process restart.
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use chrono::{DateTime, Duration, Utc};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::codec::{CodecError, WireCodec};
+use crate::core::{VehicleId, VehiclePosition};
+
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    #[error("I/O error reading or writing the archive: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid record encoding in archive segment: {0}")]
+    Codec(#[from] CodecError),
+    #[error("truncated archive segment")]
+    Truncated,
+    #[error("failed to (de)serialize archive index: {0}")]
+    Index(#[from] serde_json::Error),
+}
+
+/// Controls how eagerly [`ArchiveWriter`] flushes a segment.
+#[derive(Debug, Clone)]
+pub struct ArchiveConfig {
+    /// Flush once a segment holds this many records.
+    pub max_records_per_segment: usize,
+    /// Flush once the span between a segment's first and latest record
+    /// reaches this duration.
+    pub max_segment_span: Duration,
+}
+
+impl Default for ArchiveConfig {
+    fn default() -> Self {
+        Self { max_records_per_segment: 1000, max_segment_span: Duration::minutes(5) }
+    }
+}
+
+/// Describes one compressed segment: where it lives in the archive, what
+/// it covers, and how well it compressed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentIndexEntry {
+    pub offset: u64,
+    pub compressed_len: u32,
+    pub uncompressed_len: u32,
+    pub record_count: u32,
+    pub time_start: DateTime<Utc>,
+    pub time_end: DateTime<Utc>,
+    pub vehicle_ids: Vec<VehicleId>,
+}
+
+impl SegmentIndexEntry {
+    /// Ratio of uncompressed to compressed bytes; higher is better.
+    pub fn compression_ratio(&self) -> f32 {
+        if self.compressed_len == 0 {
+            return 0.0;
+        }
+        self.uncompressed_len as f32 / self.compressed_len as f32
+    }
+
+    fn covers(&self, vehicle_id: VehicleId, from: DateTime<Utc>, to: DateTime<Utc>) -> bool {
+        self.vehicle_ids.contains(&vehicle_id) && self.time_start <= to && self.time_end >= from
+    }
+}
+
+/// Batches [`VehiclePosition`] records into compressed segments, flushing
+/// automatically once a segment reaches `config.max_records_per_segment` or
+/// `config.max_segment_span`. Call [`ArchiveWriter::finish`] to flush any
+/// remaining partial segment and recover the index.
+pub struct ArchiveWriter<W: Write> {
+    writer: W,
+    config: ArchiveConfig,
+    pending: Vec<VehiclePosition>,
+    offset: u64,
+    index: Vec<SegmentIndexEntry>,
+}
+
+impl<W: Write> ArchiveWriter<W> {
+    pub fn new(writer: W, config: ArchiveConfig) -> Self {
+        Self { writer, config, pending: Vec::new(), offset: 0, index: Vec::new() }
+    }
+
+    /// Buffer `position`, flushing the current segment first if adding it
+    /// would exceed the configured record count or time span.
+    pub fn append(&mut self, position: &VehiclePosition) -> Result<(), ArchiveError> {
+        let span_exceeded = self
+            .pending
+            .first()
+            .map(|first| position.timestamp - first.timestamp >= self.config.max_segment_span)
+            .unwrap_or(false);
+        if span_exceeded || self.pending.len() >= self.config.max_records_per_segment {
+            self.flush_segment()?;
+        }
+        self.pending.push(position.clone());
+        Ok(())
+    }
+
+    /// Flush any buffered records as a final segment and return the index
+    /// built up over the archive's lifetime.
+    pub fn finish(mut self) -> Result<Vec<SegmentIndexEntry>, ArchiveError> {
+        self.flush_segment()?;
+        Ok(self.index)
+    }
+
+    fn flush_segment(&mut self) -> Result<(), ArchiveError> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut body = Vec::new();
+        for position in &self.pending {
+            let encoded = position.encode();
+            body.extend_from_slice(&(encoded.len() as u32).to_le_bytes());
+            body.extend_from_slice(&encoded);
+        }
+        let uncompressed_len = body.len() as u32;
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&body)?;
+        let compressed = encoder.finish()?;
+        let compressed_len = compressed.len() as u32;
+
+        self.writer.write_all(&compressed)?;
+
+        let time_start = self.pending.first().expect("checked non-empty above").timestamp;
+        let time_end = self.pending.last().expect("checked non-empty above").timestamp;
+        let mut vehicle_ids: Vec<VehicleId> = self.pending.iter().map(|p| p.vehicle_id).collect();
+        vehicle_ids.sort_unstable();
+        vehicle_ids.dedup();
+
+        self.index.push(SegmentIndexEntry {
+            offset: self.offset,
+            compressed_len,
+            uncompressed_len,
+            record_count: self.pending.len() as u32,
+            time_start,
+            time_end,
+            vehicle_ids,
+        });
+
+        self.offset += compressed_len as u64;
+        self.pending.clear();
+        Ok(())
+    }
+}
+
+/// Persist a segment index as a single JSON array, so a reloaded archive
+/// can seek to its segments without rescanning the data file.
+pub fn write_index<W: Write>(writer: W, index: &[SegmentIndexEntry]) -> Result<(), ArchiveError> {
+    serde_json::to_writer(writer, index)?;
+    Ok(())
+}
+
+/// Load a segment index previously written by [`write_index`].
+pub fn read_index<R: Read>(reader: R) -> Result<Vec<SegmentIndexEntry>, ArchiveError> {
+    Ok(serde_json::from_reader(reader)?)
+}
+
+fn decode_segment(body: &[u8]) -> Result<Vec<VehiclePosition>, ArchiveError> {
+    let mut positions = Vec::new();
+    let mut pos = 0;
+    while pos < body.len() {
+        if pos + 4 > body.len() {
+            return Err(ArchiveError::Truncated);
+        }
+        let len = u32::from_le_bytes(body[pos..pos + 4].try_into().unwrap()) as usize;
+        pos += 4;
+        if pos + len > body.len() {
+            return Err(ArchiveError::Truncated);
+        }
+        positions.push(VehiclePosition::decode(&body[pos..pos + len])?);
+        pos += len;
+    }
+    Ok(positions)
+}
+
+/// Reads segments out of an archive written by [`ArchiveWriter`], seeking
+/// directly to (and decompressing only) the segments a query could match.
+pub struct ArchiveReader<R> {
+    reader: R,
+}
+
+impl<R: Read + Seek> ArchiveReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Return every record for `vehicle_id` with a timestamp in `[from, to]`,
+    /// decompressing only the segments whose index entry could contain a
+    /// match.
+    ///
+    /// Returns a `Result` wrapping the iterator (rather than a bare `impl
+    /// Iterator`) since decompressing a segment is fallible I/O; the
+    /// matching records themselves are already fully materialized by the
+    /// time this returns, so the iterator itself never fails.
+    pub fn query(
+        &mut self,
+        index: &[SegmentIndexEntry],
+        vehicle_id: VehicleId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<impl Iterator<Item = VehiclePosition>, ArchiveError> {
+        let mut matches = Vec::new();
+        for entry in index {
+            if !entry.covers(vehicle_id, from, to) {
+                continue;
+            }
+
+            self.reader.seek(SeekFrom::Start(entry.offset))?;
+            let mut compressed = vec![0u8; entry.compressed_len as usize];
+            self.reader.read_exact(&mut compressed)?;
+
+            let mut body = Vec::with_capacity(entry.uncompressed_len as usize);
+            DeflateDecoder::new(compressed.as_slice()).read_to_end(&mut body)?;
+
+            for position in decode_segment(&body)? {
+                if position.vehicle_id == vehicle_id && position.timestamp >= from && position.timestamp <= to {
+                    matches.push(position);
+                }
+            }
+        }
+        matches.sort_by_key(|p| p.timestamp);
+        Ok(matches.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Position;
+    use std::io::Cursor;
+
+    fn sample_position(vehicle_id: VehicleId, sequence: u64, timestamp: DateTime<Utc>) -> VehiclePosition {
+        VehiclePosition {
+            vehicle_id,
+            certificate_id: None,
+            rsu_id: None,
+            position: Position {
+                lat: 1.0 + sequence as f64 * 0.0001,
+                lon: 2.0,
+                alt: None,
+                accuracy_horizontal: None,
+                accuracy_vertical: None,
+                hdop: None,
+                vdop: None,
+                tdop: None,
+                satellites_used: None,
+                satellites_visible: None,
+            },
+            velocity: None,
+            heading: None,
+            speed_accuracy: None,
+            timestamp,
+            sequence,
+            epoch: 1,
+            metadata: None,
+            sensors: Vec::new(),
+            capabilities: Vec::new(),
+            trust: None,
+            security: None,
+            network: None,
+            route_waypoints: Vec::new(),
+            emergency_vehicle: false,
+            emergency_type: crate::core::EmergencyType::NotEmergency,
+            priority_level: 0,
+            dynamics: None,
+        }
+    }
+
+    #[test]
+    fn test_write_reload_and_query_reconstructs_exactly() {
+        let vehicle_a = VehicleId::new_v4();
+        let vehicle_b = VehicleId::new_v4();
+        let base = Utc::now();
+
+        let mut storage = Vec::new();
+        let mut expected_a = Vec::new();
+        let index = {
+            let mut writer = ArchiveWriter::new(
+                &mut storage,
+                ArchiveConfig { max_records_per_segment: 100, max_segment_span: Duration::hours(1) },
+            );
+            for i in 0..3000u64 {
+                let timestamp = base + Duration::seconds(i as i64);
+                let vehicle_id = if i % 3 == 0 { vehicle_b } else { vehicle_a };
+                let position = sample_position(vehicle_id, i, timestamp);
+                if vehicle_id == vehicle_a {
+                    expected_a.push(position.clone());
+                }
+                writer.append(&position).unwrap();
+            }
+            writer.finish().unwrap()
+        };
+
+        assert!(index.len() > 1, "thousands of records at 100/segment should span multiple segments");
+
+        let mut reader = ArchiveReader::new(Cursor::new(storage));
+        let from = base;
+        let to = base + Duration::seconds(3000);
+        let results: Vec<_> = reader.query(&index, vehicle_a, from, to).unwrap().collect();
+
+        assert_eq!(results.len(), expected_a.len());
+        for (actual, expected) in results.iter().zip(expected_a.iter()) {
+            assert_eq!(actual.sequence, expected.sequence);
+            assert_eq!(actual.timestamp, expected.timestamp);
+            assert_eq!(actual.position.lat, expected.position.lat);
+        }
+    }
+
+    #[test]
+    fn test_query_filters_by_time_range() {
+        let vehicle_id = VehicleId::new_v4();
+        let base = Utc::now();
+
+        let mut storage = Vec::new();
+        let index = {
+            let mut writer = ArchiveWriter::new(&mut storage, ArchiveConfig::default());
+            for i in 0..10u64 {
+                writer.append(&sample_position(vehicle_id, i, base + Duration::seconds(i as i64))).unwrap();
+            }
+            writer.finish().unwrap()
+        };
+
+        let mut reader = ArchiveReader::new(Cursor::new(storage));
+        let results: Vec<_> = reader
+            .query(&index, vehicle_id, base + Duration::seconds(3), base + Duration::seconds(5))
+            .unwrap()
+            .collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].sequence, 3);
+        assert_eq!(results[2].sequence, 5);
+    }
+
+    #[test]
+    fn test_compression_ratio_is_reported_per_segment() {
+        let vehicle_id = VehicleId::new_v4();
+        let base = Utc::now();
+
+        let mut storage = Vec::new();
+        let index = {
+            let mut writer = ArchiveWriter::new(&mut storage, ArchiveConfig::default());
+            for i in 0..200u64 {
+                writer.append(&sample_position(vehicle_id, i, base + Duration::seconds(i as i64))).unwrap();
+            }
+            writer.finish().unwrap()
+        };
+
+        assert_eq!(index.len(), 1);
+        assert!(index[0].compression_ratio() > 1.0, "repetitive telemetry should compress well");
+    }
+
+    #[test]
+    fn test_index_survives_a_round_trip_through_disk() {
+        let vehicle_id = VehicleId::new_v4();
+        let base = Utc::now();
+
+        let mut storage = Vec::new();
+        let mut index_bytes = Vec::new();
+        {
+            let mut writer = ArchiveWriter::new(&mut storage, ArchiveConfig::default());
+            for i in 0..10u64 {
+                writer.append(&sample_position(vehicle_id, i, base + Duration::seconds(i as i64))).unwrap();
+            }
+            let index = writer.finish().unwrap();
+            write_index(&mut index_bytes, &index).unwrap();
+        }
+
+        // Simulate a process restart: the in-memory index is gone, and the
+        // only thing left is what we persisted to disk.
+        let reloaded_index = read_index(index_bytes.as_slice()).unwrap();
+
+        let mut reader = ArchiveReader::new(Cursor::new(storage));
+        let results: Vec<_> = reader
+            .query(&reloaded_index, vehicle_id, base, base + Duration::seconds(9))
+            .unwrap()
+            .collect();
+
+        assert_eq!(results.len(), 10);
+    }
+}
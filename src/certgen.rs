@@ -0,0 +1,260 @@
+//! Built-in certificate generation for bootstrapping mTLS without external PKI
+//! tooling.
+//!
+//! GeoVAN's config carries a number of TLS/CA/cert-path fields
+//! (`ca_bundle_path`, `ssl_cert`, `tls_cert`, `certificate_validation`) but
+//! until now nothing produced the files they point at. This module generates
+//! self-signed CA and leaf certificates with `rcgen` and writes them to the
+//! configured paths, so a fresh deployment can stand up mTLS for its
+//! database, Redis, RabbitMQ, and WebSocket connections on first boot.
+
+use rcgen::{Certificate, CertificateParams, DistinguishedName, DnType, SanType};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CertGenError {
+    #[error("certificate generation failed: {0}")]
+    Generation(#[from] rcgen::RcgenError),
+    #[error("failed to read/write certificate material at {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse existing certificate at {path}: {reason}")]
+    Parse { path: PathBuf, reason: String },
+}
+
+/// What a single `certgen` invocation should produce.
+#[derive(Debug, Clone)]
+pub enum CertGenMode {
+    /// Generate a fresh self-signed CA.
+    SelfSignedCa { subject: String },
+    /// Generate a leaf certificate signed by an existing CA.
+    LeafSignedByCa {
+        subject: String,
+        san: Vec<String>,
+        ca_cert_pem: String,
+        ca_key_pem: String,
+    },
+    /// Re-issue `mode` because the existing certificate's remaining
+    /// validity has dropped below the configured warning threshold, rather
+    /// than because the file was missing outright.
+    RegenerateOnExpiry(Box<CertGenMode>),
+}
+
+impl CertGenMode {
+    /// Produce the PEM material this mode describes, logging the reason
+    /// when it's a re-issue of an expiring certificate rather than a
+    /// first-time generation.
+    pub fn execute(&self) -> Result<GeneratedCert, CertGenError> {
+        match self {
+            CertGenMode::SelfSignedCa { subject } => generate_self_signed_ca(subject),
+            CertGenMode::LeafSignedByCa { subject, san, ca_cert_pem, ca_key_pem } => {
+                generate_leaf_cert(subject, san, ca_cert_pem, ca_key_pem)
+            }
+            CertGenMode::RegenerateOnExpiry(mode) => {
+                tracing::info!("re-issuing certificate: remaining validity below warning threshold");
+                mode.execute()
+            }
+        }
+    }
+}
+
+/// A generated key/cert pair, PEM-encoded.
+#[derive(Debug, Clone)]
+pub struct GeneratedCert {
+    pub cert_pem: String,
+    pub key_pem: String,
+}
+
+/// Where a single identity's generated material should be written.
+#[derive(Debug, Clone)]
+pub struct CertPaths {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+/// Generate a new self-signed CA certificate.
+pub fn generate_self_signed_ca(subject: &str) -> Result<GeneratedCert, CertGenError> {
+    let mut params = CertificateParams::new(vec![subject.to_string()]);
+    let mut dn = DistinguishedName::new();
+    dn.push(DnType::CommonName, subject);
+    params.distinguished_name = dn;
+    params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+
+    let cert = Certificate::from_params(params)?;
+    Ok(GeneratedCert {
+        cert_pem: cert.serialize_pem()?,
+        key_pem: cert.serialize_private_key_pem(),
+    })
+}
+
+/// Generate a leaf certificate signed by the given CA.
+pub fn generate_leaf_cert(
+    subject: &str,
+    san: &[String],
+    ca_cert_pem: &str,
+    ca_key_pem: &str,
+) -> Result<GeneratedCert, CertGenError> {
+    let mut params = CertificateParams::new(san.to_vec());
+    let mut dn = DistinguishedName::new();
+    dn.push(DnType::CommonName, subject);
+    params.distinguished_name = dn;
+    params.subject_alt_names = san
+        .iter()
+        .cloned()
+        .map(SanType::DnsName)
+        .collect();
+
+    let leaf = Certificate::from_params(params)?;
+    let ca_params = CertificateParams::from_ca_cert_pem(ca_cert_pem, rcgen::KeyPair::from_pem(ca_key_pem)?)?;
+    let ca_cert = Certificate::from_params(ca_params)?;
+
+    Ok(GeneratedCert {
+        cert_pem: leaf.serialize_pem_with_signer(&ca_cert)?,
+        key_pem: leaf.serialize_private_key_pem(),
+    })
+}
+
+/// Whether the PEM certificate at `cert_path` is missing or within `warning`
+/// of expiring and therefore needs (re)generation. An existing file that
+/// cannot be read or parsed is reported as an error, not as `true`.
+pub fn needs_generation(cert_path: &Path, warning: Duration) -> Result<bool, CertGenError> {
+    if !cert_path.exists() {
+        return Ok(true);
+    }
+
+    let pem = std::fs::read_to_string(cert_path).map_err(|source| CertGenError::Io {
+        path: cert_path.to_path_buf(),
+        source,
+    })?;
+
+    let (_, cert) = x509_parser::pem::parse_x509_pem(pem.as_bytes())
+        .map_err(|e| CertGenError::Parse { path: cert_path.to_path_buf(), reason: e.to_string() })?;
+    let x509 = cert
+        .parse_x509()
+        .map_err(|e| CertGenError::Parse { path: cert_path.to_path_buf(), reason: e.to_string() })?;
+
+    let not_after = x509.validity().not_after.timestamp();
+    let warn_at = chrono::Utc::now().timestamp() + warning.as_secs() as i64;
+    Ok(not_after <= warn_at)
+}
+
+/// Write a generated cert/key pair to disk, creating parent directories as
+/// needed. Existing files are overwritten.
+pub fn write_pair(paths: &CertPaths, generated: &GeneratedCert) -> Result<(), CertGenError> {
+    for path in [&paths.cert_path, &paths.key_path] {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|source| CertGenError::Io {
+                path: path.clone(),
+                source,
+            })?;
+        }
+    }
+
+    std::fs::write(&paths.cert_path, &generated.cert_pem).map_err(|source| CertGenError::Io {
+        path: paths.cert_path.clone(),
+        source,
+    })?;
+    std::fs::write(&paths.key_path, &generated.key_pem).map_err(|source| CertGenError::Io {
+        path: paths.key_path.clone(),
+        source,
+    })?;
+
+    restrict_key_permissions(&paths.key_path)?;
+
+    Ok(())
+}
+
+/// Lock the private key file down to owner read/write only. Generated keys
+/// are live mTLS material; the process umask alone (typically 0644) is not
+/// an acceptable default for them.
+#[cfg(unix)]
+fn restrict_key_permissions(key_path: &Path) -> Result<(), CertGenError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::set_permissions(key_path, std::fs::Permissions::from_mode(0o600)).map_err(
+        |source| CertGenError::Io {
+            path: key_path.to_path_buf(),
+            source,
+        },
+    )
+}
+
+#[cfg(not(unix))]
+fn restrict_key_permissions(_key_path: &Path) -> Result<(), CertGenError> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_self_signed_ca() {
+        let ca = generate_self_signed_ca("GeoVAN Root CA").unwrap();
+        assert!(ca.cert_pem.contains("BEGIN CERTIFICATE"));
+        assert!(ca.key_pem.contains("BEGIN"));
+    }
+
+    #[test]
+    fn test_generate_leaf_signed_by_ca() {
+        let ca = generate_self_signed_ca("GeoVAN Root CA").unwrap();
+        let leaf = generate_leaf_cert(
+            "geovan-postgres",
+            &["geovan-postgres".to_string()],
+            &ca.cert_pem,
+            &ca.key_pem,
+        )
+        .unwrap();
+        assert!(leaf.cert_pem.contains("BEGIN CERTIFICATE"));
+    }
+
+    #[test]
+    fn test_needs_generation_when_missing() {
+        let missing = PathBuf::from("/tmp/geovan-certgen-test-does-not-exist.pem");
+        assert!(needs_generation(&missing, Duration::from_secs(2_592_000)).unwrap());
+    }
+
+    #[test]
+    fn test_regenerate_on_expiry_delegates_to_inner_mode() {
+        let mode = CertGenMode::RegenerateOnExpiry(Box::new(CertGenMode::SelfSignedCa {
+            subject: "GeoVAN Root CA".to_string(),
+        }));
+        let generated = mode.execute().unwrap();
+        assert!(generated.cert_pem.contains("BEGIN CERTIFICATE"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_write_pair_restricts_key_file_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!(
+            "geovan-certgen-test-{}",
+            std::process::id()
+        ));
+        let paths = CertPaths {
+            cert_path: dir.join("leaf.pem"),
+            key_path: dir.join("leaf.key.pem"),
+        };
+        let ca = generate_self_signed_ca("GeoVAN Root CA").unwrap();
+        let leaf = generate_leaf_cert(
+            "geovan-postgres",
+            &["geovan-postgres".to_string()],
+            &ca.cert_pem,
+            &ca.key_pem,
+        )
+        .unwrap();
+
+        write_pair(&paths, &leaf).unwrap();
+
+        let mode = std::fs::metadata(&paths.key_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,15 +1,77 @@
+use arc_swap::ArcSwap;
+use base64::Engine;
 use config::{Config as ConfigSource, Environment, File};
+use notify::{RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use socket2::{Domain, Socket, Type};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::channel;
+use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
 
+use crate::certgen;
+use crate::secrets::{Secret, SecretSource};
+
+/// Try to bind `host:port` with `SO_REUSEADDR` disabled, then immediately
+/// drop the socket to release it. A successful bind only proves the port was
+/// free at this instant; it is a best-effort pre-flight, not a reservation.
+fn try_reserve_port(host: &str, port: u16) -> std::io::Result<()> {
+    let addr: SocketAddr = format!("{host}:{port}").parse().map_err(|e| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("invalid address: {e}"))
+    })?;
+    let socket = Socket::new(Domain::for_address(addr), Type::STREAM, None)?;
+    socket.set_reuse_address(false)?;
+    socket.bind(&addr.into())?;
+    socket.listen(1)?;
+    Ok(())
+}
+
+/// Derive a conventional private-key path alongside a certificate path, e.g.
+/// `ca-bundle.crt` -> `ca-bundle.key`.
+fn certgen_sibling_key_path(cert_path: &Path) -> PathBuf {
+    cert_path.with_extension("key")
+}
+
+/// Wrap `base` in `CertGenMode::RegenerateOnExpiry` when `existed` is true,
+/// so `ensure_certificates` logs a re-issue rather than a first-time
+/// generation for certs that are already on disk but past the warning
+/// threshold.
+fn certgen_mode(existed: bool, base: certgen::CertGenMode) -> certgen::CertGenMode {
+    if existed {
+        certgen::CertGenMode::RegenerateOnExpiry(Box::new(base))
+    } else {
+        base
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ConfigError {
     #[error("Failed to load configuration: {0}")]
     LoadError(#[from] config::ConfigError),
     #[error("Invalid configuration value: {0}")]
     ValidationError(String),
+    #[error("Failed to watch configuration file: {0}")]
+    WatchError(String),
+    #[error("Failed to resolve secret for {field}: {source}")]
+    SecretResolution {
+        field: &'static str,
+        #[source]
+        source: crate::secrets::SecretError,
+    },
+    #[error("Certificate generation failed: {0}")]
+    CertGen(#[from] crate::certgen::CertGenError),
+}
+
+/// Controls how `Vec` fields behave when an override layer is merged in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VecMergeStrategy {
+    /// Replace the base `Vec` entirely with the override's `Vec`
+    #[default]
+    Replace,
+    /// Append the override's items onto the base `Vec`
+    Append,
 }
 
 pub type Result<T> = std::result::Result<T, ConfigError>;
@@ -43,12 +105,21 @@ pub struct Config {
     
     /// Monitoring configuration
     pub monitoring: MonitoringConfig,
-    
+
+    /// Logging/observability configuration
+    pub logging: LoggingConfig,
+
     /// Performance configuration
     pub performance: PerformanceConfig,
     
     /// Privacy configuration
     pub privacy: PrivacyConfig,
+
+    /// DNS resolution configuration
+    pub dns: DnsConfig,
+
+    /// Object-storage backend configuration
+    pub storage: StorageConfig,
 }
 
 /// Application-level configuration
@@ -71,6 +142,13 @@ pub struct AppConfig {
     
     /// Temporary directory
     pub temp_dir: PathBuf,
+
+    /// Attempt to bind every configured host:port pair during `validate()`
+    /// and fail fast on conflicts instead of discovering them deep into
+    /// startup. Off by default so unit tests and containerized
+    /// environments (where the OS-level check is meaningless or racy)
+    /// aren't forced to opt out individually.
+    pub reserve_ports_on_startup: bool,
 }
 
 /// Database configuration
@@ -99,6 +177,32 @@ pub struct DatabaseConfig {
     
     /// SSL CA path
     pub ssl_ca: Option<PathBuf>,
+
+    /// Retry policy used when establishing pooled connections
+    pub retry: RetryConfig,
+}
+
+/// Full-jitter exponential backoff policy for establishing pooled
+/// connections: the delay before attempt `n` is a random value in
+/// `[0, min(max, base * 2^n)]`, giving up after `max_retries`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Initial backoff delay
+    pub base: Duration,
+    /// Backoff delay cap
+    pub max: Duration,
+    /// Maximum number of attempts before giving up
+    pub max_retries: u32,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_millis(100),
+            max: Duration::from_secs(10),
+            max_retries: 5,
+        }
+    }
 }
 
 /// SSL mode for database connections
@@ -135,6 +239,9 @@ pub struct RedisConfig {
     
     /// TLS certificate path
     pub tls_cert: Option<PathBuf>,
+
+    /// Retry policy used when establishing pooled connections
+    pub retry: RetryConfig,
 }
 
 /// RabbitMQ configuration
@@ -142,40 +249,44 @@ pub struct RedisConfig {
 pub struct RabbitMQConfig {
     /// RabbitMQ URL
     pub url: String,
-    
+
     /// Connection timeout
     pub connection_timeout: Duration,
-    
+
     /// Heartbeat interval
     pub heartbeat: Duration,
-    
+
     /// Channel timeout
     pub channel_timeout: Duration,
-    
+
     /// Enable TLS
     pub tls: bool,
-    
+
     /// TLS certificate path
     pub tls_cert: Option<PathBuf>,
+
+    /// Retry policy used when establishing pooled connections
+    pub retry: RetryConfig,
 }
 
 /// Security configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityConfig {
-    /// JWT secret key
-    pub jwt_secret: String,
-    
+    /// JWT secret key source: `inline:...`, `env:VAR_NAME`, `file:/path`,
+    /// `imds`/`credential-chain`. Resolved lazily via [`SecretSource::resolve`].
+    pub jwt_secret: SecretSource,
+
     /// JWT expiration time
     pub jwt_expiry: Duration,
-    
+
     /// Refresh token expiration time
     pub refresh_token_expiry: Duration,
-    
+
     /// Certificate authority bundle path
     pub ca_bundle_path: PathBuf,
-    
-    /// Encryption key (32 bytes, base64 encoded)
-    pub encryption_key: String,
+
+    /// Encryption key source (resolved value must be 32 bytes, base64 encoded)
+    pub encryption_key: SecretSource,
     
     /// Key rotation interval
     pub key_rotation_interval: Duration,
@@ -203,6 +314,12 @@ pub struct SecurityConfig {
     
     /// CRL check interval
     pub crl_check_interval: Duration,
+
+    /// Bootstrap mTLS material with [`Config::ensure_certificates`] on
+    /// every startup. Off by default so tests and deployments that bring
+    /// their own externally-issued certificates aren't surprised by a
+    /// self-signed CA appearing under their configured paths.
+    pub auto_generate_certificates: bool,
 }
 
 /// API configuration
@@ -253,9 +370,15 @@ pub struct WebSocketConfig {
     
     /// Heartbeat interval
     pub heartbeat_interval: Duration,
-    
+
     /// Enable compression
     pub compression: bool,
+
+    /// TLS certificate path
+    pub tls_cert: Option<PathBuf>,
+
+    /// TLS key path
+    pub tls_key: Option<PathBuf>,
 }
 
 /// Monitoring configuration
@@ -283,6 +406,61 @@ pub struct MonitoringConfig {
     pub log_endpoint: Option<String>,
 }
 
+/// Output format for structured log records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable, multi-line output; the default for local development.
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log-shipping pipelines.
+    Json,
+}
+
+/// How the rotating file appender rolls over to a new file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogRotation {
+    #[default]
+    Daily,
+    Hourly,
+    Never,
+}
+
+/// Where to ship logs emitted to the syslog sink, and under what RFC 5424
+/// facility/identity. Only consulted when the `syslog` cargo feature is
+/// enabled; the fields still parse without it so config files don't need to
+/// be conditional on the feature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyslogConfig {
+    pub host: String,
+    pub port: u16,
+    /// RFC 5424 facility number (e.g. 1 = user-level, 16-23 = local0-local7).
+    pub facility: u8,
+}
+
+/// Logging/observability configuration consumed by [`crate::init`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggingConfig {
+    /// `pretty` for local development, `json` for log-shipping pipelines.
+    pub format: LogFormat,
+
+    /// Directory for the rotating file appender. `None` disables file
+    /// logging; stdout logging is always on.
+    pub directory: Option<PathBuf>,
+
+    /// How the file appender rolls over.
+    pub rotation: LogRotation,
+
+    /// Per-module level filters layered on top of `app.log_level`, e.g.
+    /// `{"sqlx": "warn", "geovan::security": "debug"}`.
+    pub module_filters: std::collections::HashMap<String, String>,
+
+    /// RFC 5424 syslog sink, active only when built with the `syslog`
+    /// feature.
+    pub syslog: Option<SyslogConfig>,
+}
+
 /// Performance configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceConfig {
@@ -336,24 +514,518 @@ pub struct PrivacyConfig {
     pub zero_knowledge_proofs: bool,
 }
 
+/// Transport used to reach upstream DNS servers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DnsProtocol {
+    #[default]
+    Udp,
+    /// DNS-over-HTTPS, via `dns.doh_url`.
+    Doh,
+    /// DNS-over-TLS.
+    Dot,
+}
+
+/// Configuration for `utils::dns`'s caching resolver.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsConfig {
+    /// Upstream resolver addresses, e.g. `["1.1.1.1", "9.9.9.9"]`. Bare
+    /// IPs only — the port is supplied per-protocol in
+    /// `CachingResolver::from_config`.
+    pub upstream_servers: Vec<String>,
+
+    /// How lookups reach the upstream servers.
+    pub protocol: DnsProtocol,
+
+    /// DNS-over-HTTPS endpoint, required when `protocol` is `Doh`.
+    pub doh_url: Option<String>,
+
+    /// Maximum number of distinct hostnames held in the LRU cache.
+    pub cache_size: usize,
+
+    /// Upper bound on how long a cached answer is trusted, even if the
+    /// upstream TTL is larger.
+    pub max_cache_ttl: Duration,
+}
+
+/// Object-storage backend selection, parsed from the `[storage]` TOML table.
+///
+/// Retained location data and archived telemetry are written through
+/// whichever backend is selected here instead of only `app.data_dir`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "backend", rename_all = "lowercase")]
+pub enum StorageConfig {
+    /// Store on the local filesystem, under `app.data_dir` unless overridden
+    Local {
+        /// Directory to store data in; defaults to `app.data_dir` when unset
+        path: Option<PathBuf>,
+    },
+    /// Amazon S3 or an S3-compatible object store
+    S3 {
+        bucket: String,
+        region: String,
+        /// Override endpoint for S3-compatible stores (MinIO, R2, etc.)
+        endpoint: Option<String>,
+        prefix: Option<String>,
+        access_key_id: Option<String>,
+        secret_access_key: Option<String>,
+    },
+    /// Google Cloud Storage
+    Gcs {
+        bucket: String,
+        prefix: Option<String>,
+        /// Path to a service-account JSON key file
+        credentials_path: Option<PathBuf>,
+    },
+    /// Azure Blob Storage
+    Azure {
+        container: String,
+        account: String,
+        prefix: Option<String>,
+        access_key: Option<String>,
+    },
+}
+
+impl StorageConfig {
+    /// Validate that the required fields for the selected backend are present.
+    fn validate(&self) -> Result<()> {
+        match self {
+            StorageConfig::Local { .. } => Ok(()),
+            StorageConfig::S3 { bucket, region, .. } => {
+                if bucket.is_empty() {
+                    return Err(ConfigError::ValidationError("storage.bucket".to_string()));
+                }
+                if region.is_empty() {
+                    return Err(ConfigError::ValidationError("storage.region".to_string()));
+                }
+                Ok(())
+            }
+            StorageConfig::Gcs { bucket, .. } => {
+                if bucket.is_empty() {
+                    return Err(ConfigError::ValidationError("storage.bucket".to_string()));
+                }
+                Ok(())
+            }
+            StorageConfig::Azure { container, account, .. } => {
+                if container.is_empty() {
+                    return Err(ConfigError::ValidationError("storage.container".to_string()));
+                }
+                if account.is_empty() {
+                    return Err(ConfigError::ValidationError("storage.account".to_string()));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        StorageConfig::Local { path: None }
+    }
+}
+
+/// A partially-populated configuration layer, typically deserialized from an
+/// environment-specific TOML file, where every field is optional so that
+/// `Config::merge` can tell "not present in this layer" apart from "present
+/// and equal to the default".
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConfigOverride {
+    pub app: Option<AppConfigOverride>,
+    pub database: Option<DatabaseConfigOverride>,
+    pub redis: Option<RedisConfigOverride>,
+    pub rabbitmq: Option<RabbitMQConfigOverride>,
+    pub security: Option<SecurityConfigOverride>,
+    pub api: Option<ApiConfigOverride>,
+    pub websocket: Option<WebSocketConfigOverride>,
+    pub monitoring: Option<MonitoringConfigOverride>,
+    pub logging: Option<LoggingConfigOverride>,
+    pub performance: Option<PerformanceConfigOverride>,
+    pub privacy: Option<PrivacyConfigOverride>,
+    pub dns: Option<DnsConfigOverride>,
+    /// Storage backends aren't merged field-by-field — a layer that sets
+    /// `[storage]` replaces the selection wholesale.
+    pub storage: Option<StorageConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppConfigOverride {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub environment: Option<String>,
+    pub log_level: Option<String>,
+    pub data_dir: Option<PathBuf>,
+    pub temp_dir: Option<PathBuf>,
+    pub reserve_ports_on_startup: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseConfigOverride {
+    pub url: Option<String>,
+    pub max_connections: Option<u32>,
+    pub connection_timeout: Option<Duration>,
+    pub query_timeout: Option<Duration>,
+    pub ssl_mode: Option<SslMode>,
+    pub ssl_cert: Option<PathBuf>,
+    pub ssl_key: Option<PathBuf>,
+    pub ssl_ca: Option<PathBuf>,
+    pub retry: Option<RetryConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RedisConfigOverride {
+    pub url: Option<String>,
+    pub pool_size: Option<u32>,
+    pub connection_timeout: Option<Duration>,
+    pub read_timeout: Option<Duration>,
+    pub write_timeout: Option<Duration>,
+    pub tls: Option<bool>,
+    pub tls_cert: Option<PathBuf>,
+    pub retry: Option<RetryConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RabbitMQConfigOverride {
+    pub url: Option<String>,
+    pub connection_timeout: Option<Duration>,
+    pub heartbeat: Option<Duration>,
+    pub channel_timeout: Option<Duration>,
+    pub tls: Option<bool>,
+    pub tls_cert: Option<PathBuf>,
+    pub retry: Option<RetryConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SecurityConfigOverride {
+    pub jwt_secret: Option<SecretSource>,
+    pub jwt_expiry: Option<Duration>,
+    pub refresh_token_expiry: Option<Duration>,
+    pub ca_bundle_path: Option<PathBuf>,
+    pub encryption_key: Option<SecretSource>,
+    pub key_rotation_interval: Option<Duration>,
+    pub max_login_attempts: Option<u32>,
+    pub lockout_duration: Option<Duration>,
+    pub rate_limiting: Option<bool>,
+    pub rate_limit_per_minute: Option<u32>,
+    pub certificate_validation: Option<bool>,
+    pub cert_expiry_warning: Option<Duration>,
+    pub ocsp_stapling: Option<bool>,
+    pub crl_check_interval: Option<Duration>,
+    pub auto_generate_certificates: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiConfigOverride {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub base_path: Option<String>,
+    pub cors_origins: Option<Vec<String>>,
+    pub request_timeout: Option<Duration>,
+    pub max_request_size: Option<usize>,
+    pub compression: Option<bool>,
+    pub metrics_enabled: Option<bool>,
+    pub metrics_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebSocketConfigOverride {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub max_connections: Option<u32>,
+    pub connection_timeout: Option<Duration>,
+    pub heartbeat_interval: Option<Duration>,
+    pub compression: Option<bool>,
+    pub tls_cert: Option<PathBuf>,
+    pub tls_key: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonitoringConfigOverride {
+    pub prometheus_port: Option<u16>,
+    pub grafana_port: Option<u16>,
+    pub health_check_interval: Option<Duration>,
+    pub metrics_interval: Option<Duration>,
+    pub tracing_enabled: Option<bool>,
+    pub jaeger_endpoint: Option<String>,
+    pub log_endpoint: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoggingConfigOverride {
+    pub format: Option<LogFormat>,
+    pub directory: Option<PathBuf>,
+    pub rotation: Option<LogRotation>,
+    pub module_filters: Option<std::collections::HashMap<String, String>>,
+    pub syslog: Option<SyslogConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PerformanceConfigOverride {
+    pub worker_threads: Option<usize>,
+    pub max_concurrent_requests: Option<usize>,
+    pub cache_ttl: Option<Duration>,
+    pub db_pool_size: Option<u32>,
+    pub redis_pool_size: Option<u32>,
+    pub queue_buffer_size: Option<usize>,
+    pub connection_pooling: Option<bool>,
+    pub query_caching: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PrivacyConfigOverride {
+    pub pseudonym_rotation_interval: Option<Duration>,
+    pub location_noise_stddev: Option<f64>,
+    pub data_retention_days: Option<u32>,
+    pub anonymization_enabled: Option<bool>,
+    pub differential_privacy: Option<bool>,
+    pub privacy_budget: Option<f64>,
+    pub zero_knowledge_proofs: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DnsConfigOverride {
+    pub upstream_servers: Option<Vec<String>>,
+    pub protocol: Option<DnsProtocol>,
+    pub doh_url: Option<String>,
+    pub cache_size: Option<usize>,
+    pub max_cache_ttl: Option<Duration>,
+}
+
+/// Fills `$base.$field` from `$over.$field` whenever the override is `Some`
+macro_rules! apply_field {
+    ($base:expr, $over:expr, $field:ident) => {
+        if let Some(value) = $over.$field {
+            $base.$field = value;
+        }
+    };
+}
+
+impl AppConfig {
+    fn apply_override(&mut self, over: AppConfigOverride) {
+        apply_field!(self, over, name);
+        apply_field!(self, over, version);
+        apply_field!(self, over, environment);
+        apply_field!(self, over, log_level);
+        apply_field!(self, over, data_dir);
+        apply_field!(self, over, temp_dir);
+        apply_field!(self, over, reserve_ports_on_startup);
+    }
+}
+
+impl DatabaseConfig {
+    fn apply_override(&mut self, over: DatabaseConfigOverride) {
+        apply_field!(self, over, url);
+        apply_field!(self, over, max_connections);
+        apply_field!(self, over, connection_timeout);
+        apply_field!(self, over, query_timeout);
+        apply_field!(self, over, ssl_mode);
+        if over.ssl_cert.is_some() {
+            self.ssl_cert = over.ssl_cert;
+        }
+        if over.ssl_key.is_some() {
+            self.ssl_key = over.ssl_key;
+        }
+        if over.ssl_ca.is_some() {
+            self.ssl_ca = over.ssl_ca;
+        }
+        apply_field!(self, over, retry);
+    }
+}
+
+impl RedisConfig {
+    fn apply_override(&mut self, over: RedisConfigOverride) {
+        apply_field!(self, over, url);
+        apply_field!(self, over, pool_size);
+        apply_field!(self, over, connection_timeout);
+        apply_field!(self, over, read_timeout);
+        apply_field!(self, over, write_timeout);
+        apply_field!(self, over, tls);
+        if over.tls_cert.is_some() {
+            self.tls_cert = over.tls_cert;
+        }
+        apply_field!(self, over, retry);
+    }
+}
+
+impl RabbitMQConfig {
+    fn apply_override(&mut self, over: RabbitMQConfigOverride) {
+        apply_field!(self, over, url);
+        apply_field!(self, over, connection_timeout);
+        apply_field!(self, over, heartbeat);
+        apply_field!(self, over, channel_timeout);
+        apply_field!(self, over, tls);
+        if over.tls_cert.is_some() {
+            self.tls_cert = over.tls_cert;
+        }
+        apply_field!(self, over, retry);
+    }
+}
+
+impl SecurityConfig {
+    fn apply_override(&mut self, over: SecurityConfigOverride) {
+        apply_field!(self, over, jwt_secret);
+        apply_field!(self, over, jwt_expiry);
+        apply_field!(self, over, refresh_token_expiry);
+        apply_field!(self, over, ca_bundle_path);
+        apply_field!(self, over, encryption_key);
+        apply_field!(self, over, key_rotation_interval);
+        apply_field!(self, over, max_login_attempts);
+        apply_field!(self, over, lockout_duration);
+        apply_field!(self, over, rate_limiting);
+        apply_field!(self, over, rate_limit_per_minute);
+        apply_field!(self, over, certificate_validation);
+        apply_field!(self, over, cert_expiry_warning);
+        apply_field!(self, over, ocsp_stapling);
+        apply_field!(self, over, crl_check_interval);
+        apply_field!(self, over, auto_generate_certificates);
+    }
+}
+
+impl SecurityConfig {
+    /// Resolve the configured JWT secret source into its actual value.
+    pub fn resolve_jwt_secret(&self) -> Result<Secret> {
+        self.jwt_secret
+            .resolve()
+            .map_err(|source| ConfigError::SecretResolution { field: "security.jwt_secret", source })
+    }
+
+    /// Resolve the configured encryption key source into its actual value.
+    pub fn resolve_encryption_key(&self) -> Result<Secret> {
+        self.encryption_key
+            .resolve()
+            .map_err(|source| ConfigError::SecretResolution { field: "security.encryption_key", source })
+    }
+}
+
+impl ApiConfig {
+    fn apply_override(&mut self, over: ApiConfigOverride, vec_strategy: VecMergeStrategy) {
+        apply_field!(self, over, host);
+        apply_field!(self, over, port);
+        apply_field!(self, over, base_path);
+        if let Some(mut cors_origins) = over.cors_origins {
+            match vec_strategy {
+                VecMergeStrategy::Replace => self.cors_origins = cors_origins,
+                VecMergeStrategy::Append => self.cors_origins.append(&mut cors_origins),
+            }
+        }
+        apply_field!(self, over, request_timeout);
+        apply_field!(self, over, max_request_size);
+        apply_field!(self, over, compression);
+        apply_field!(self, over, metrics_enabled);
+        apply_field!(self, over, metrics_path);
+    }
+}
+
+impl WebSocketConfig {
+    fn apply_override(&mut self, over: WebSocketConfigOverride) {
+        apply_field!(self, over, host);
+        apply_field!(self, over, port);
+        apply_field!(self, over, max_connections);
+        apply_field!(self, over, connection_timeout);
+        apply_field!(self, over, heartbeat_interval);
+        apply_field!(self, over, compression);
+        if over.tls_cert.is_some() {
+            self.tls_cert = over.tls_cert;
+        }
+        if over.tls_key.is_some() {
+            self.tls_key = over.tls_key;
+        }
+    }
+}
+
+impl MonitoringConfig {
+    fn apply_override(&mut self, over: MonitoringConfigOverride) {
+        apply_field!(self, over, prometheus_port);
+        apply_field!(self, over, grafana_port);
+        apply_field!(self, over, health_check_interval);
+        apply_field!(self, over, metrics_interval);
+        apply_field!(self, over, tracing_enabled);
+        if over.jaeger_endpoint.is_some() {
+            self.jaeger_endpoint = over.jaeger_endpoint;
+        }
+        if over.log_endpoint.is_some() {
+            self.log_endpoint = over.log_endpoint;
+        }
+    }
+}
+
+impl LoggingConfig {
+    fn apply_override(&mut self, over: LoggingConfigOverride) {
+        apply_field!(self, over, format);
+        apply_field!(self, over, rotation);
+        apply_field!(self, over, module_filters);
+        if over.directory.is_some() {
+            self.directory = over.directory;
+        }
+        if over.syslog.is_some() {
+            self.syslog = over.syslog;
+        }
+    }
+}
+
+impl PerformanceConfig {
+    fn apply_override(&mut self, over: PerformanceConfigOverride) {
+        apply_field!(self, over, worker_threads);
+        apply_field!(self, over, max_concurrent_requests);
+        apply_field!(self, over, cache_ttl);
+        apply_field!(self, over, db_pool_size);
+        apply_field!(self, over, redis_pool_size);
+        apply_field!(self, over, queue_buffer_size);
+        apply_field!(self, over, connection_pooling);
+        apply_field!(self, over, query_caching);
+    }
+}
+
+impl PrivacyConfig {
+    fn apply_override(&mut self, over: PrivacyConfigOverride) {
+        apply_field!(self, over, pseudonym_rotation_interval);
+        apply_field!(self, over, location_noise_stddev);
+        apply_field!(self, over, data_retention_days);
+        apply_field!(self, over, anonymization_enabled);
+        apply_field!(self, over, differential_privacy);
+        apply_field!(self, over, privacy_budget);
+        apply_field!(self, over, zero_knowledge_proofs);
+    }
+}
+
+impl DnsConfig {
+    fn apply_override(&mut self, over: DnsConfigOverride) {
+        apply_field!(self, over, upstream_servers);
+        apply_field!(self, over, protocol);
+        apply_field!(self, over, cache_size);
+        apply_field!(self, over, max_cache_ttl);
+        if over.doh_url.is_some() {
+            self.doh_url = over.doh_url;
+        }
+    }
+}
+
 impl Config {
     /// Load configuration from file and environment variables
     pub fn load() -> Result<Self> {
         let config_path = std::env::var("GEOVAN_CONFIG")
             .map(PathBuf::from)
             .unwrap_or_else(|_| PathBuf::from("config/geovan.toml"));
-        
+
+        Self::load_from(&config_path)
+    }
+
+    /// Load configuration from `path`, then `config/default.toml` (whose
+    /// keys take precedence over `path`'s, since it is added later), then
+    /// `GEOVAN_`-prefixed environment variables, validating the result. Shared by [`Config::load`] and [`Config::watch`] so both the
+    /// initial load and every hot-reload read from the same file.
+    fn load_from(path: &Path) -> Result<Self> {
         let mut config = ConfigSource::builder()
-            .add_source(File::from(config_path.as_path()).required(false))
+            .add_source(File::from(path).required(false))
             .add_source(File::from("config/default.toml").required(false))
             .add_source(Environment::with_prefix("GEOVAN").separator("_"))
             .build()?;
-        
+
         let config: Config = config.try_deserialize()?;
-        
+
         // Validate configuration
         config.validate()?;
-        
+
         Ok(config)
     }
     
@@ -374,16 +1046,23 @@ impl Config {
             return Err(ConfigError::ValidationError("RabbitMQ URL cannot be empty".to_string()));
         }
         
-        // Validate JWT secret
-        if self.security.jwt_secret.len() < 32 {
+        // Validate JWT secret (resolved through its SecretSource)
+        let jwt_secret = self.security.resolve_jwt_secret()?;
+        if jwt_secret.len() < 32 {
             return Err(ConfigError::ValidationError("JWT secret must be at least 32 characters".to_string()));
         }
-        
-        // Validate encryption key
-        if self.security.encryption_key.len() != 44 { // base64 encoded 32 bytes
-            return Err(ConfigError::ValidationError("Encryption key must be 32 bytes (base64 encoded)".to_string()));
+
+        // Validate encryption key: the *resolved* value must base64-decode to
+        // exactly 32 bytes, not merely be 44 characters long.
+        let encryption_key = self.security.resolve_encryption_key()?;
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(encryption_key.as_bytes())
+            .map_err(|_| ConfigError::ValidationError("Encryption key is not valid base64".to_string()))?;
+        if decoded.len() != 32 {
+            return Err(ConfigError::ValidationError("Encryption key must decode to 32 bytes".to_string()));
         }
-        
+
+
         // Validate ports
         if self.api.port == 0 || self.websocket.port == 0 {
             return Err(ConfigError::ValidationError("Ports cannot be 0".to_string()));
@@ -393,36 +1072,260 @@ impl Config {
         if self.database.connection_timeout.as_secs() == 0 {
             return Err(ConfigError::ValidationError("Database connection timeout cannot be 0".to_string()));
         }
-        
+
+        // Validate syslog facility (RFC 5424 defines 0-23; past that,
+        // format_datagram's `facility * 8 + severity` yields a PRI outside
+        // RFC 5424's range, and from 32 on it overflows its `u8` arithmetic)
+        if let Some(syslog) = &self.logging.syslog {
+            if syslog.facility > 23 {
+                return Err(ConfigError::ValidationError("Syslog facility must be in 0-23".to_string()));
+            }
+        }
+
+        // Validate storage backend
+        self.storage.validate()?;
+
+        // Pre-flight port reservation (opt-in: see AppConfig::reserve_ports_on_startup)
+        if self.app.reserve_ports_on_startup {
+            self.preflight_ports()?;
+        }
+
         Ok(())
     }
+
+    /// Check every configured host:port pair for intra-config duplicates and
+    /// OS-level availability, aggregating every conflict into a single
+    /// `ValidationError` naming each unavailable `host:port` and the
+    /// subsystem that wanted it, rather than failing deep into startup.
+    fn preflight_ports(&self) -> Result<()> {
+        let pairs: Vec<(String, u16, &str)> = vec![
+            (self.api.host.clone(), self.api.port, "api"),
+            (self.websocket.host.clone(), self.websocket.port, "websocket"),
+            ("0.0.0.0".to_string(), self.monitoring.prometheus_port, "monitoring.prometheus"),
+            ("0.0.0.0".to_string(), self.monitoring.grafana_port, "monitoring.grafana"),
+        ];
+
+        let mut conflicts = Vec::new();
+
+        // Intra-config duplicates, checked before touching the OS.
+        for i in 0..pairs.len() {
+            for j in (i + 1)..pairs.len() {
+                let (host_a, port_a, name_a) = &pairs[i];
+                let (host_b, port_b, name_b) = &pairs[j];
+                if host_a == host_b && port_a == port_b {
+                    conflicts.push(format!("{host_a}:{port_a} requested by both {name_a} and {name_b}"));
+                }
+            }
+        }
+
+        // OS-level availability, one bind-and-release per pair.
+        for (host, port, name) in &pairs {
+            if let Err(e) = try_reserve_port(host, *port) {
+                conflicts.push(format!("{host}:{port} ({name}) unavailable: {e}"));
+            }
+        }
+
+        if conflicts.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::ValidationError(format!("port conflicts detected: {}", conflicts.join("; "))))
+        }
+    }
     
     /// Get configuration for a specific environment
     pub fn for_environment(env: &str) -> Result<Self> {
         let mut config = Self::load()?;
-        
+
         // Override with environment-specific settings
         let env_config_path = format!("config/geovan.{}.toml", env);
         if std::path::Path::new(&env_config_path).exists() {
             let env_config = ConfigSource::builder()
                 .add_source(File::from(env_config_path.as_str()))
                 .build()?;
-            
-            let env_config: Config = env_config.try_deserialize()?;
-            
+
+            let env_override: ConfigOverride = env_config.try_deserialize()?;
+
             // Merge configurations (environment overrides base)
-            config = config.merge(env_config)?;
+            config = config.merge(env_override, VecMergeStrategy::Replace);
+            config.validate()?;
         }
-        
+
         Ok(config)
     }
-    
-    /// Merge with another configuration
-    fn merge(self, other: Config) -> Result<Self> {
-        // This is a simplified merge - in practice, you'd want more sophisticated merging
-        Ok(other)
+
+    /// Merge a (partial) override layer into this configuration.
+    ///
+    /// Every field in `other` that is `Some` overrides the corresponding
+    /// field in `self`; fields left `None` in `other` mean "not present in
+    /// this layer" and are left untouched. `vec_strategy` controls whether
+    /// `Vec` fields (currently just `cors_origins`) replace or are appended
+    /// to when present in `other`.
+    pub fn merge(mut self, other: ConfigOverride, vec_strategy: VecMergeStrategy) -> Self {
+        if let Some(app) = other.app {
+            self.app.apply_override(app);
+        }
+        if let Some(database) = other.database {
+            self.database.apply_override(database);
+        }
+        if let Some(redis) = other.redis {
+            self.redis.apply_override(redis);
+        }
+        if let Some(rabbitmq) = other.rabbitmq {
+            self.rabbitmq.apply_override(rabbitmq);
+        }
+        if let Some(security) = other.security {
+            self.security.apply_override(security);
+        }
+        if let Some(api) = other.api {
+            self.api.apply_override(api, vec_strategy);
+        }
+        if let Some(websocket) = other.websocket {
+            self.websocket.apply_override(websocket);
+        }
+        if let Some(monitoring) = other.monitoring {
+            self.monitoring.apply_override(monitoring);
+        }
+        if let Some(logging) = other.logging {
+            self.logging.apply_override(logging);
+        }
+        if let Some(performance) = other.performance {
+            self.performance.apply_override(performance);
+        }
+        if let Some(privacy) = other.privacy {
+            self.privacy.apply_override(privacy);
+        }
+        if let Some(dns) = other.dns {
+            self.dns.apply_override(dns);
+        }
+        if let Some(storage) = other.storage {
+            self.storage = storage;
+        }
+        self
     }
-    
+
+    /// Watch `path` for changes and keep a live, hot-reloaded configuration.
+    ///
+    /// Spawns a background file-watcher thread that reloads and re-validates
+    /// `path` (as [`Config::load_from`] does) whenever it changes, publishing the new value into the returned
+    /// `ArcSwap` so subsystems holding a clone can observe it without a
+    /// restart. If a reload fails validation (or fails to load at all), the
+    /// previously-published good configuration is kept in place and
+    /// `callback` is invoked with the `ConfigError` instead of tearing
+    /// anything down.
+    pub fn watch(
+        path: impl AsRef<Path>,
+        callback: impl Fn(ConfigError) + Send + 'static,
+    ) -> Result<Arc<ArcSwap<Config>>> {
+        let initial = Self::load_from(path.as_ref())?;
+        let current = Arc::new(ArcSwap::from_pointee(initial));
+        let watched_path = path.as_ref().to_path_buf();
+        let swap_handle = Arc::clone(&current);
+
+        // Watch the parent directory rather than the file itself and filter
+        // events by filename. Editors' atomic saves, `kubectl` ConfigMap
+        // symlink swaps, and config-reloader-style tools all replace the file
+        // via rename rather than in-place write; on inotify-backed platforms
+        // that detaches a watch held on the file's own inode, silently ending
+        // all future reloads after the first such edit.
+        let watch_dir = watched_path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let watched_name = watched_path.file_name().map(|name| name.to_os_string());
+
+        let (tx, rx) = channel();
+        let mut watcher = notify::recommended_watcher(tx)
+            .map_err(|e| ConfigError::WatchError(e.to_string()))?;
+        watcher
+            .watch(&watch_dir, RecursiveMode::NonRecursive)
+            .map_err(|e| ConfigError::WatchError(e.to_string()))?;
+
+        std::thread::spawn(move || {
+            // Keep the watcher alive for the lifetime of the thread.
+            let _watcher = watcher;
+            for event in rx {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(_) => continue,
+                };
+                let touches_watched_file = event
+                    .paths
+                    .iter()
+                    .any(|changed| changed.file_name() == watched_name.as_deref());
+                if !touches_watched_file {
+                    continue;
+                }
+                match Self::load_from(&watched_path) {
+                    Ok(reloaded) => swap_handle.store(Arc::new(reloaded)),
+                    Err(err) => callback(err),
+                }
+            }
+        });
+
+        Ok(current)
+    }
+
+    /// Idempotently bootstrap mTLS material for the database, Redis,
+    /// RabbitMQ, and WebSocket connections.
+    ///
+    /// Generates a self-signed CA (populating `security.ca_bundle_path`) the
+    /// first time it's called, then issues a leaf certificate signed by that
+    /// CA for each configured `*_cert`/`tls_cert` path that is missing.
+    /// Certificates whose remaining validity has dropped below
+    /// `security.cert_expiry_warning` are re-issued. Safe to call on every
+    /// startup. [`crate::init`] calls this automatically when
+    /// `security.auto_generate_certificates` is set.
+    pub fn ensure_certificates(&self) -> Result<()> {
+        let ca_key_path = certgen_sibling_key_path(&self.security.ca_bundle_path);
+        let warning = self.security.cert_expiry_warning;
+
+        if certgen::needs_generation(&self.security.ca_bundle_path, warning)? {
+            let mode = certgen_mode(
+                self.security.ca_bundle_path.exists(),
+                certgen::CertGenMode::SelfSignedCa { subject: "GeoVAN Root CA".to_string() },
+            );
+            let ca = mode.execute()?;
+            certgen::write_pair(
+                &certgen::CertPaths { cert_path: self.security.ca_bundle_path.clone(), key_path: ca_key_path.clone() },
+                &ca,
+            )?;
+        }
+
+        let ca_cert_pem = std::fs::read_to_string(&self.security.ca_bundle_path)
+            .map_err(|source| crate::certgen::CertGenError::Io { path: self.security.ca_bundle_path.clone(), source })?;
+        let ca_key_pem = std::fs::read_to_string(&ca_key_path)
+            .map_err(|source| crate::certgen::CertGenError::Io { path: ca_key_path.clone(), source })?;
+
+        let leaves: Vec<(&str, Option<PathBuf>, Option<PathBuf>)> = vec![
+            ("geovan-postgres", self.database.ssl_cert.clone(), self.database.ssl_key.clone()),
+            ("geovan-redis", self.redis.tls_cert.clone(), None),
+            ("geovan-rabbitmq", self.rabbitmq.tls_cert.clone(), None),
+            ("geovan-websocket", self.websocket.tls_cert.clone(), self.websocket.tls_key.clone()),
+        ];
+
+        for (subject, cert_path, key_path) in leaves {
+            let Some(cert_path) = cert_path else { continue };
+            let key_path = key_path.unwrap_or_else(|| certgen_sibling_key_path(&cert_path));
+
+            if certgen::needs_generation(&cert_path, warning)? {
+                let mode = certgen_mode(
+                    cert_path.exists(),
+                    certgen::CertGenMode::LeafSignedByCa {
+                        subject: subject.to_string(),
+                        san: vec![subject.to_string()],
+                        ca_cert_pem: ca_cert_pem.clone(),
+                        ca_key_pem: ca_key_pem.clone(),
+                    },
+                );
+                let leaf = mode.execute()?;
+                certgen::write_pair(&certgen::CertPaths { cert_path, key_path }, &leaf)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Check if running in development mode
     pub fn is_development(&self) -> bool {
         self.app.environment == "development"
@@ -459,8 +1362,11 @@ impl Default for Config {
             api: ApiConfig::default(),
             websocket: WebSocketConfig::default(),
             monitoring: MonitoringConfig::default(),
+            logging: LoggingConfig::default(),
             performance: PerformanceConfig::default(),
             privacy: PrivacyConfig::default(),
+            dns: DnsConfig::default(),
+            storage: StorageConfig::default(),
         }
     }
 }
@@ -474,6 +1380,7 @@ impl Default for AppConfig {
             log_level: "info".to_string(),
             data_dir: PathBuf::from("data"),
             temp_dir: PathBuf::from("tmp"),
+            reserve_ports_on_startup: false,
         }
     }
 }
@@ -489,6 +1396,7 @@ impl Default for DatabaseConfig {
             ssl_cert: None,
             ssl_key: None,
             ssl_ca: None,
+            retry: RetryConfig::default(),
         }
     }
 }
@@ -503,6 +1411,7 @@ impl Default for RedisConfig {
             write_timeout: Duration::from_secs(3),
             tls: false,
             tls_cert: None,
+            retry: RetryConfig::default(),
         }
     }
 }
@@ -516,6 +1425,7 @@ impl Default for RabbitMQConfig {
             channel_timeout: Duration::from_secs(30),
             tls: false,
             tls_cert: None,
+            retry: RetryConfig::default(),
         }
     }
 }
@@ -523,11 +1433,11 @@ impl Default for RabbitMQConfig {
 impl Default for SecurityConfig {
     fn default() -> Self {
         Self {
-            jwt_secret: "your-super-secret-jwt-key-change-in-production".to_string(),
+            jwt_secret: SecretSource::Inline("your-super-secret-jwt-key-change-in-production".to_string()),
             jwt_expiry: Duration::from_secs(3600), // 1 hour
             refresh_token_expiry: Duration::from_secs(604800), // 7 days
             ca_bundle_path: PathBuf::from("/etc/ssl/certs/ca-bundle.crt"),
-            encryption_key: "dGVzdC1rZXktZm9yLWRldmVsb3BtZW50LW9ubHk=".to_string(), // base64 encoded
+            encryption_key: SecretSource::Inline("dGVzdC1rZXktZm9yLWRldmVsb3BtZW50LW9ubHkhITA=".to_string()), // 32 bytes, base64 encoded
             key_rotation_interval: Duration::from_secs(86400), // 24 hours
             max_login_attempts: 5,
             lockout_duration: Duration::from_secs(900), // 15 minutes
@@ -537,6 +1447,7 @@ impl Default for SecurityConfig {
             cert_expiry_warning: Duration::from_secs(2592000), // 30 days
             ocsp_stapling: true,
             crl_check_interval: Duration::from_secs(3600), // 1 hour
+            auto_generate_certificates: false,
         }
     }
 }
@@ -566,6 +1477,8 @@ impl Default for WebSocketConfig {
             connection_timeout: Duration::from_secs(30),
             heartbeat_interval: Duration::from_secs(30),
             compression: true,
+            tls_cert: None,
+            tls_key: None,
         }
     }
 }
@@ -584,6 +1497,18 @@ impl Default for MonitoringConfig {
     }
 }
 
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::default(),
+            directory: None,
+            rotation: LogRotation::default(),
+            module_filters: std::collections::HashMap::new(),
+            syslog: None,
+        }
+    }
+}
+
 impl Default for PerformanceConfig {
     fn default() -> Self {
         Self {
@@ -613,6 +1538,18 @@ impl Default for PrivacyConfig {
     }
 }
 
+impl Default for DnsConfig {
+    fn default() -> Self {
+        Self {
+            upstream_servers: vec!["1.1.1.1".to_string(), "8.8.8.8".to_string()],
+            protocol: DnsProtocol::default(),
+            doh_url: None,
+            cache_size: 1024,
+            max_cache_ttl: Duration::from_secs(300),
+        }
+    }
+}
+
 impl std::fmt::Display for SslMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -657,7 +1594,204 @@ mod tests {
         
         // Test invalid JWT secret
         config.database.url = "postgresql://localhost:5432/geovan".to_string();
-        config.security.jwt_secret = "short".to_string();
+        config.security.jwt_secret = SecretSource::Inline("short".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_watch_reloads_from_the_watched_path_not_the_default() {
+        let path = std::env::temp_dir().join(format!("geovan-config-watch-test-{}.json", uuid::Uuid::new_v4()));
+
+        let mut initial = Config::default();
+        initial.app.name = "watched-initial".to_string();
+        std::fs::write(&path, serde_json::to_string(&initial).unwrap()).unwrap();
+
+        let current = Config::watch(&path, |_| {}).unwrap();
+        assert_eq!(current.load().app.name, "watched-initial");
+
+        let mut updated = Config::default();
+        updated.app.name = "watched-reloaded".to_string();
+        std::fs::write(&path, serde_json::to_string(&updated).unwrap()).unwrap();
+
+        let mut observed = current.load().app.name.clone();
+        for _ in 0..50 {
+            if observed == "watched-reloaded" {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+            observed = current.load().app.name.clone();
+        }
+        assert_eq!(observed, "watched-reloaded", "reload should have read the watched path, not the default");
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_watch_reloads_after_atomic_rename_replacing_the_file() {
+        let dir = std::env::temp_dir().join(format!("geovan-config-watch-rename-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+        let tmp_path = dir.join("config.json.tmp");
+
+        let mut initial = Config::default();
+        initial.app.name = "watched-initial".to_string();
+        std::fs::write(&path, serde_json::to_string(&initial).unwrap()).unwrap();
+
+        let current = Config::watch(&path, |_| {}).unwrap();
+        assert_eq!(current.load().app.name, "watched-initial");
+
+        // Simulate an editor's atomic save: write to a temp file, then rename
+        // it over the watched path, rather than writing in place.
+        let mut updated = Config::default();
+        updated.app.name = "watched-reloaded".to_string();
+        std::fs::write(&tmp_path, serde_json::to_string(&updated).unwrap()).unwrap();
+        std::fs::rename(&tmp_path, &path).unwrap();
+
+        let mut observed = current.load().app.name.clone();
+        for _ in 0..50 {
+            if observed == "watched-reloaded" {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+            observed = current.load().app.name.clone();
+        }
+        assert_eq!(observed, "watched-reloaded", "reload should survive a rename-based replace of the watched file");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_merge_overrides_only_present_fields() {
+        let base = Config::default();
+        let original_host = base.api.host.clone();
+
+        let over = ConfigOverride {
+            api: Some(ApiConfigOverride {
+                port: Some(9999),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let merged = base.merge(over, VecMergeStrategy::Replace);
+        assert_eq!(merged.api.port, 9999);
+        // Fields absent from the override layer are untouched.
+        assert_eq!(merged.api.host, original_host);
+    }
+
+    #[test]
+    fn test_merge_vec_strategy() {
+        let base = Config::default();
+        let over = ConfigOverride {
+            api: Some(ApiConfigOverride {
+                cors_origins: Some(vec!["https://example.com".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let appended = base.clone().merge(over.clone(), VecMergeStrategy::Append);
+        assert_eq!(appended.api.cors_origins.len(), 2);
+
+        let replaced = base.merge(over, VecMergeStrategy::Replace);
+        assert_eq!(replaced.api.cors_origins, vec!["https://example.com".to_string()]);
+    }
+
+    #[test]
+    fn test_storage_config_local_is_valid() {
+        assert!(StorageConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_encryption_key_must_decode_to_32_bytes() {
+        let mut config = Config::default();
+        assert!(config.validate().is_ok());
+
+        // 44 characters but decodes to fewer than 32 bytes must now fail,
+        // whereas the old string-length check would have accepted it.
+        config.security.encryption_key =
+            SecretSource::Inline("dGVzdC1rZXktZm9yLWRldmVsb3BtZW50LW9ubHkhIQ==".to_string());
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_syslog_facility_out_of_range_is_rejected() {
+        let mut config = Config::default();
+        config.logging.syslog = Some(SyslogConfig { host: "localhost".to_string(), port: 514, facility: 24 });
+        let err = config.validate().unwrap_err();
+        assert!(matches!(err, ConfigError::ValidationError(_)));
+    }
+
+    #[test]
+    fn test_syslog_facility_at_max_valid_value_is_accepted() {
+        let mut config = Config::default();
+        config.logging.syslog = Some(SyslogConfig { host: "localhost".to_string(), port: 514, facility: 23 });
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_storage_config_s3_requires_bucket_and_region() {
+        let missing_bucket = StorageConfig::S3 {
+            bucket: "".to_string(),
+            region: "us-east-1".to_string(),
+            endpoint: None,
+            prefix: None,
+            access_key_id: None,
+            secret_access_key: None,
+        };
+        assert!(missing_bucket.validate().is_err());
+
+        let valid = StorageConfig::S3 {
+            bucket: "geovan-telemetry".to_string(),
+            region: "us-east-1".to_string(),
+            endpoint: None,
+            prefix: None,
+            access_key_id: None,
+            secret_access_key: None,
+        };
+        assert!(valid.validate().is_ok());
+    }
+
+    #[test]
+    fn test_auto_generate_certificates_off_by_default() {
+        let config = Config::default();
+        assert!(!config.security.auto_generate_certificates);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_preflight_ports_off_by_default() {
+        let config = Config::default();
+        assert!(!config.app.reserve_ports_on_startup);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_preflight_ports_detects_intra_config_duplicate() {
+        let mut config = Config::default();
+        config.app.reserve_ports_on_startup = true;
+        config.api.host = "127.0.0.1".to_string();
+        config.websocket.host = "127.0.0.1".to_string();
+        config.websocket.port = config.api.port;
+
+        let err = config.validate().unwrap_err();
+        assert!(matches!(err, ConfigError::ValidationError(_)));
+        assert!(err.to_string().contains("requested by both"));
+    }
+
+    #[test]
+    fn test_dns_config_override_replaces_upstream_servers() {
+        let mut config = Config::default();
+        let over = ConfigOverride {
+            dns: Some(DnsConfigOverride {
+                upstream_servers: Some(vec!["9.9.9.9".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        config = config.merge(over, VecMergeStrategy::Replace);
+        assert_eq!(config.dns.upstream_servers, vec!["9.9.9.9".to_string()]);
+        assert_eq!(config.dns.protocol, DnsProtocol::Udp);
+    }
 }
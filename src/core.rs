@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use thiserror::Error;
 
 // Re-export common types
 pub use uuid::Uuid as VehicleId;
@@ -49,6 +50,119 @@ pub struct Position {
     pub satellites_visible: Option<u32>,
 }
 
+/// Errors parsing or constructing a [`Position`] from an RFC 5870 `geo:`
+/// URI or a raw coordinate tuple.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum GeoUriError {
+    #[error("not a valid geo: URI: {0}")]
+    Malformed(String),
+    #[error("coordinate out of range: {0}")]
+    OutOfBounds(String),
+}
+
+fn validate_lat_lon(lat: f64, lon: f64) -> Result<(), GeoUriError> {
+    if lat < -90.0 || lat > 90.0 {
+        return Err(GeoUriError::OutOfBounds(format!("latitude {} out of range", lat)));
+    }
+    if lon < -180.0 || lon > 180.0 {
+        return Err(GeoUriError::OutOfBounds(format!("longitude {} out of range", lon)));
+    }
+    Ok(())
+}
+
+impl Position {
+    /// Serialize to an RFC 5870 `geo:` URI, e.g. `geo:40.7128,-74.006,12;u=5`.
+    /// Altitude is included when present; uncertainty (`u=`) is derived
+    /// from `accuracy_horizontal`.
+    pub fn to_geo_uri(&self) -> String {
+        let mut uri = format!("geo:{},{}", self.lat, self.lon);
+        if let Some(alt) = self.alt {
+            uri.push_str(&format!(",{}", alt));
+        }
+        if let Some(uncertainty) = self.accuracy_horizontal {
+            uri.push_str(&format!(";u={}", uncertainty));
+        }
+        uri
+    }
+
+    /// Parse an RFC 5870 `geo:` URI, including the optional altitude
+    /// coordinate and `u=` uncertainty parameter. Lat/lon bounds are
+    /// validated the same way [`Validatable::validate`] checks them.
+    pub fn from_geo_uri(uri: &str) -> Result<Position, GeoUriError> {
+        let rest = uri.strip_prefix("geo:").ok_or_else(|| GeoUriError::Malformed(uri.to_string()))?;
+        let mut segments = rest.split(';');
+        let coords = segments.next().ok_or_else(|| GeoUriError::Malformed(uri.to_string()))?;
+
+        let mut coord_parts = coords.split(',');
+        let lat: f64 = coord_parts
+            .next()
+            .ok_or_else(|| GeoUriError::Malformed(uri.to_string()))?
+            .parse()
+            .map_err(|_| GeoUriError::Malformed(uri.to_string()))?;
+        let lon: f64 = coord_parts
+            .next()
+            .ok_or_else(|| GeoUriError::Malformed(uri.to_string()))?
+            .parse()
+            .map_err(|_| GeoUriError::Malformed(uri.to_string()))?;
+        let alt = match coord_parts.next() {
+            Some(raw) => Some(raw.parse::<f64>().map_err(|_| GeoUriError::Malformed(uri.to_string()))?),
+            None => None,
+        };
+
+        let mut accuracy_horizontal = None;
+        for param in segments {
+            if let Some(raw) = param.strip_prefix("u=") {
+                accuracy_horizontal = Some(raw.parse::<f32>().map_err(|_| GeoUriError::Malformed(uri.to_string()))?);
+            }
+        }
+
+        validate_lat_lon(lat, lon)?;
+
+        Ok(Position {
+            lat,
+            lon,
+            alt,
+            accuracy_horizontal,
+            accuracy_vertical: None,
+            hdop: None,
+            vdop: None,
+            tdop: None,
+            satellites_used: None,
+            satellites_visible: None,
+        })
+    }
+}
+
+impl TryFrom<(f64, f64)> for Position {
+    type Error = GeoUriError;
+
+    fn try_from((lat, lon): (f64, f64)) -> Result<Self, Self::Error> {
+        validate_lat_lon(lat, lon)?;
+        Ok(Position {
+            lat,
+            lon,
+            alt: None,
+            accuracy_horizontal: None,
+            accuracy_vertical: None,
+            hdop: None,
+            vdop: None,
+            tdop: None,
+            satellites_used: None,
+            satellites_visible: None,
+        })
+    }
+}
+
+impl TryFrom<(f64, f64, f64)> for Position {
+    type Error = GeoUriError;
+
+    fn try_from((lat, lon, alt): (f64, f64, f64)) -> Result<Self, Self::Error> {
+        let mut position = Position::try_from((lat, lon))?;
+        position.alt = Some(alt);
+        Ok(position)
+    }
+}
+
 /// 3D velocity vector with accuracy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Velocity {
@@ -517,6 +631,55 @@ pub struct VehiclePosition {
     
     /// Priority level
     pub priority_level: u32,
+
+    /// Per-wheel dynamics and body-frame motion, when the vehicle reports it
+    pub dynamics: Option<Dynamics>,
+}
+
+/// Per-wheel telemetry for one corner of the vehicle, modeled on the
+/// detailed channel set exposed by race-sim telemetry APIs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WheelTelemetry {
+    /// Wheel rotation speed (rad/s)
+    pub rotation_speed: f32,
+
+    /// Suspension deflection from rest position (m, positive = compressed)
+    pub suspension_deflection: f32,
+
+    /// Tire surface temperature (Celsius)
+    pub tire_temperature: f32,
+
+    /// Brake disc/pad temperature (Celsius)
+    pub brake_temperature: f32,
+
+    /// Fraction of available tire grip currently in use (0.0-1.0)
+    pub grip_fraction: f32,
+}
+
+/// Per-wheel dynamics and local (body-frame) motion, too fine-grained for
+/// [`Velocity`]'s single world-frame vector but needed to detect rollover
+/// risk, wheel slip, and loss of traction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dynamics {
+    /// Front-left wheel telemetry
+    pub front_left: WheelTelemetry,
+
+    /// Front-right wheel telemetry
+    pub front_right: WheelTelemetry,
+
+    /// Rear-left wheel telemetry
+    pub rear_left: WheelTelemetry,
+
+    /// Rear-right wheel telemetry
+    pub rear_right: WheelTelemetry,
+
+    /// Roll/pitch/yaw-frame rotational acceleration in the vehicle body
+    /// coordinate system (rad/s²)
+    pub local_rot_accel: [f32; 3],
+
+    /// Roll/pitch/yaw-frame linear acceleration in the vehicle body
+    /// coordinate system (m/s²)
+    pub local_accel: [f32; 3],
 }
 
 /// Vehicle cluster for traffic analysis
@@ -563,6 +726,17 @@ pub enum ClusterType {
     SpecialEvent = 7,             // Special event traffic
 }
 
+/// How an asserted/expected location for a vehicle was obtained. RSU- and
+/// GPS-authoritative sources are trusted outright; anything else is subject
+/// to the distance-based trust multiplier in `utils`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LocationSource {
+    SelfReported = 0,             // Vehicle's own reported position
+    NetworkTriangulation = 1,     // Triangulated from network infrastructure
+    RsuVerified = 2,              // Corroborated by a roadside unit
+    GpsAuthoritative = 3,         // Authoritative GPS reference source
+}
+
 /// Trust score update
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrustScoreUpdate {
@@ -588,6 +762,58 @@ pub struct TrustScoreUpdate {
     pub change: f32,
 }
 
+/// Per-epoch trust samples for a vehicle, biased toward recent behavior.
+/// A single static [`TrustScore`] can't distinguish a vehicle that's
+/// consistently well-behaved from one that misbehaved once and has simply
+/// gone quiet; decaying older samples means reputation has to be re-earned
+/// rather than permanently banked.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrustHistory {
+    samples: Vec<(u64, TrustScore)>,
+}
+
+impl TrustHistory {
+    pub fn new() -> Self {
+        Self { samples: Vec::new() }
+    }
+
+    /// Record a trust sample observed at `epoch`.
+    pub fn record(&mut self, epoch: u64, score: TrustScore) {
+        self.samples.push((epoch, score));
+    }
+
+    /// All recorded samples, in the order they were recorded (not sorted
+    /// by epoch).
+    pub fn samples(&self) -> &[(u64, TrustScore)] {
+        &self.samples
+    }
+
+    /// Exponentially-decayed trust as of `now_epoch`: each sample's weight
+    /// is `0.5^((now_epoch - epoch) / half_life_epochs)`, so a sample
+    /// `half_life_epochs` old counts for half as much as a fresh one. The
+    /// weighted pairs are fed into [`utils::weighted_trust_average`] to
+    /// reuse the same aggregation the rest of the trust system relies on.
+    pub fn decayed_trust(&self, now_epoch: u64, half_life_epochs: f32) -> TrustScore {
+        let weighted: Vec<(TrustScore, f32)> = self
+            .samples
+            .iter()
+            .map(|(epoch, score)| {
+                let age_epochs = now_epoch.saturating_sub(*epoch) as f32;
+                let weight = 0.5f32.powf(age_epochs / half_life_epochs);
+                (*score, weight)
+            })
+            .collect();
+        utils::weighted_trust_average(&weighted)
+    }
+
+    /// Drop samples older than `max_age_epochs`, measured from
+    /// [`utils::current_epoch`].
+    pub fn prune(&mut self, max_age_epochs: u64) {
+        let now = utils::current_epoch();
+        self.samples.retain(|(epoch, _)| now.saturating_sub(*epoch) <= max_age_epochs);
+    }
+}
+
 /// System status message
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemStatus {
@@ -740,6 +966,20 @@ impl Trusted for VehiclePosition {
     }
 }
 
+impl VehiclePosition {
+    /// Fold a distance-to-asserted-location trust multiplier for `asserted`
+    /// (e.g. an RSU's corroborating fix or a prior trusted position) into
+    /// this vehicle's trust metrics, so `Trusted::trust_score` and
+    /// `Trusted::is_trusted` reflect it. A no-op when `trust` hasn't been
+    /// computed yet, since there's no baseline `overall_score` to fold the
+    /// multiplier into.
+    pub fn apply_location_trust(&mut self, asserted: &Position, source: LocationSource) {
+        if let Some(trust) = self.trust.as_mut() {
+            utils::apply_location_trust_factor(trust, &self.position, asserted, source);
+        }
+    }
+}
+
 impl Validatable for VehiclePosition {
     fn validate(&self) -> Result<(), String> {
         // Validate position coordinates
@@ -862,6 +1102,282 @@ pub mod utils {
         let weighted_sum: f32 = scores.iter().map(|(score, weight)| score * weight).sum();
         weighted_sum / total_weight
     }
+
+    /// Within this distance (meters) of the asserted location, a report is
+    /// fully trusted.
+    const TIGHT_RADIUS_METERS: f64 = 30.0;
+
+    /// Within this distance (meters), a report is only partially trusted.
+    const MIDDLE_RADIUS_METERS: f64 = 50.0;
+
+    const TIGHT_RADIUS_MULTIPLIER: TrustScore = 1.0;
+    const MIDDLE_RADIUS_MULTIPLIER: TrustScore = 0.25;
+    const BEYOND_MIDDLE_RADIUS_MULTIPLIER: TrustScore = 0.0;
+
+    /// Weight given to the location-assertion factor when it is folded into
+    /// a vehicle's overall trust score.
+    const LOCATION_ASSERTION_WEIGHT: f32 = 0.2;
+
+    /// Derive a trust multiplier from how far a `reported` position is from
+    /// an `asserted`/expected one. RSU-verified and GPS-authoritative
+    /// sources are assumed corroborated and always return 1.0; everything
+    /// else is stepped down by distance so spoofed or drifting GPS degrades
+    /// trust automatically. The 0.0 case is reachable (not clamped away) so
+    /// a badly-placed vehicle can be fully distrusted.
+    pub fn asserted_distance_to_trust_multiplier(reported: &Position, asserted: &Position, source: LocationSource) -> TrustScore {
+        if matches!(source, LocationSource::RsuVerified | LocationSource::GpsAuthoritative) {
+            return 1.0;
+        }
+
+        let distance = calculate_distance(reported, asserted);
+        if distance <= TIGHT_RADIUS_METERS {
+            TIGHT_RADIUS_MULTIPLIER
+        } else if distance <= MIDDLE_RADIUS_METERS {
+            MIDDLE_RADIUS_MULTIPLIER
+        } else {
+            BEYOND_MIDDLE_RADIUS_MULTIPLIER
+        }
+    }
+
+    /// Average the distance-to-asserted-location multiplier across a
+    /// window of recent `(reported, asserted)` position pairs. If the mean
+    /// distance exceeds `threshold_m`, the vehicle is ineligible for any
+    /// location-based trust and this returns 0.0 outright; otherwise it
+    /// returns the mean of the per-report multiplier.
+    pub fn average_distance_trust(recent: &[(Position, Position)], threshold_m: f64) -> TrustScore {
+        if recent.is_empty() {
+            return 0.0;
+        }
+
+        let mean_distance: f64 = recent
+            .iter()
+            .map(|(reported, asserted)| calculate_distance(reported, asserted))
+            .sum::<f64>()
+            / recent.len() as f64;
+        if mean_distance > threshold_m {
+            return 0.0;
+        }
+
+        let total: TrustScore = recent
+            .iter()
+            .map(|(reported, asserted)| asserted_distance_to_trust_multiplier(reported, asserted, LocationSource::SelfReported))
+            .sum();
+        total / recent.len() as f32
+    }
+
+    /// Fold a distance-to-asserted-location trust multiplier into `trust`,
+    /// recording it as a [`TrustFactor`] and re-deriving `overall_score`
+    /// via [`weighted_trust_average`] so `Trusted::is_trusted` and
+    /// `Trusted::trust_score` pick it up automatically. See
+    /// [`VehiclePosition::apply_location_trust`] for the call site that
+    /// reaches this through a real `VehiclePosition`.
+    pub fn apply_location_trust_factor(
+        trust: &mut TrustMetrics,
+        reported: &Position,
+        asserted: &Position,
+        source: LocationSource,
+    ) {
+        let multiplier = asserted_distance_to_trust_multiplier(reported, asserted, source);
+        trust.factors.push(TrustFactor {
+            name: "location_assertion".to_string(),
+            weight: LOCATION_ASSERTION_WEIGHT,
+            score: multiplier,
+            description: format!("distance-to-asserted-location multiplier ({:?})", source),
+            last_calculation: current_timestamp_ms(),
+        });
+        trust.overall_score = weighted_trust_average(&[
+            (trust.overall_score, 1.0 - LOCATION_ASSERTION_WEIGHT),
+            (multiplier, LOCATION_ASSERTION_WEIGHT),
+        ]);
+    }
+
+    /// Base tolerance for wheel-speed divergence while driving straight (rad/s)
+    const WHEEL_SPEED_DIVERGENCE_BASE_TOLERANCE: f32 = 0.5;
+
+    /// Extra tolerance granted per degree (or degree/s) of actual turning signal
+    const WHEEL_SPEED_DIVERGENCE_PER_DEGREE: f32 = 0.02;
+
+    /// Flag [`AnomalyType::ErraticMovement`] when the left/right wheel-speed
+    /// divergence on either axle is larger than what the reported steering
+    /// angle and body yaw rate can explain.
+    pub fn detect_erratic_wheel_speeds(dynamics: &Dynamics, steering_angle_deg: f32, yaw_rate_deg_s: f32) -> Option<AnomalyType> {
+        let front_diff = (dynamics.front_left.rotation_speed - dynamics.front_right.rotation_speed).abs();
+        let rear_diff = (dynamics.rear_left.rotation_speed - dynamics.rear_right.rotation_speed).abs();
+        let max_diff = front_diff.max(rear_diff);
+
+        let turning_signal = steering_angle_deg.abs() + yaw_rate_deg_s.abs();
+        let tolerance = WHEEL_SPEED_DIVERGENCE_BASE_TOLERANCE + turning_signal * WHEEL_SPEED_DIVERGENCE_PER_DEGREE;
+
+        if max_diff > tolerance {
+            Some(AnomalyType::ErraticMovement)
+        } else {
+            None
+        }
+    }
+
+    /// Rotate a body-frame vector into the ENU world frame using the
+    /// standard ZYX Euler sequence: yaw about Z (compass heading), then
+    /// pitch about Y, then roll about X.
+    pub fn body_to_world(accel_local: [f32; 3], heading_deg: f32, pitch_deg: f32, roll_deg: f32) -> [f32; 3] {
+        apply_matrix(&rotation_matrix(heading_deg, pitch_deg, roll_deg), accel_local)
+    }
+
+    /// Inverse of [`body_to_world`]: rotate a world-frame (ENU) vector back
+    /// into the vehicle's body frame. The ZYX rotation matrix is
+    /// orthogonal, so its inverse is just its transpose.
+    pub fn world_to_body(accel_world: [f32; 3], heading_deg: f32, pitch_deg: f32, roll_deg: f32) -> [f32; 3] {
+        apply_matrix(&transpose(&rotation_matrix(heading_deg, pitch_deg, roll_deg)), accel_world)
+    }
+
+    fn rotation_matrix(heading_deg: f32, pitch_deg: f32, roll_deg: f32) -> [[f32; 3]; 3] {
+        let (sy, cy) = heading_deg.to_radians().sin_cos();
+        let (sp, cp) = pitch_deg.to_radians().sin_cos();
+        let (sr, cr) = roll_deg.to_radians().sin_cos();
+
+        [
+            [cy * cp, cy * sp * sr - sy * cr, cy * sp * cr + sy * sr],
+            [sy * cp, sy * sp * sr + cy * cr, sy * sp * cr - cy * sr],
+            [-sp, cp * sr, cp * cr],
+        ]
+    }
+
+    fn transpose(m: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+        [
+            [m[0][0], m[1][0], m[2][0]],
+            [m[0][1], m[1][1], m[2][1]],
+            [m[0][2], m[1][2], m[2][2]],
+        ]
+    }
+
+    fn apply_matrix(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+        [
+            m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+            m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+            m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+        ]
+    }
+
+    /// Group `positions` by vehicle, preserving each vehicle's first
+    /// appearance order, with each group ordered by `sequence` then
+    /// `timestamp`.
+    fn group_by_vehicle_ordered(positions: &[VehiclePosition]) -> Vec<(VehicleId, Vec<&VehiclePosition>)> {
+        let mut order = Vec::new();
+        let mut groups: HashMap<VehicleId, Vec<&VehiclePosition>> = HashMap::new();
+        for position in positions {
+            groups.entry(position.vehicle_id).or_insert_with(|| {
+                order.push(position.vehicle_id);
+                Vec::new()
+            }).push(position);
+        }
+        order
+            .into_iter()
+            .map(|id| {
+                let mut points = groups.remove(&id).unwrap();
+                points.sort_by_key(|p| (p.sequence, p.timestamp));
+                (id, points)
+            })
+            .collect()
+    }
+
+    /// Render a time-ordered slice of [`VehiclePosition`] as a GPX 1.1
+    /// document, one `<trk>`/`<trkseg>` per vehicle. `speed`, `heading`,
+    /// `hdop`/`vdop`/`satellites_used` ride along as `<extensions>` since
+    /// GPX's base schema has no slot for them.
+    pub fn to_gpx(positions: &[VehiclePosition]) -> String {
+        let mut gpx = String::new();
+        gpx.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        gpx.push_str("<gpx version=\"1.1\" creator=\"geovan\" xmlns=\"http://www.topografix.com/GPX/1/1\">\n");
+
+        for (vehicle_id, points) in group_by_vehicle_ordered(positions) {
+            gpx.push_str("  <trk>\n");
+            gpx.push_str(&format!("    <name>{}</name>\n", vehicle_id));
+            gpx.push_str("    <trkseg>\n");
+            for position in points {
+                gpx.push_str(&format!(
+                    "      <trkpt lat=\"{}\" lon=\"{}\">\n",
+                    position.position.lat, position.position.lon
+                ));
+                if let Some(alt) = position.position.alt {
+                    gpx.push_str(&format!("        <ele>{}</ele>\n", alt));
+                }
+                gpx.push_str(&format!("        <time>{}</time>\n", position.timestamp.to_rfc3339()));
+
+                let has_extensions = position.velocity.is_some()
+                    || position.heading.is_some()
+                    || position.position.hdop.is_some()
+                    || position.position.vdop.is_some()
+                    || position.position.satellites_used.is_some();
+                if has_extensions {
+                    gpx.push_str("        <extensions>\n");
+                    if let Some(velocity) = &position.velocity {
+                        gpx.push_str(&format!("          <speed>{}</speed>\n", velocity.speed));
+                    }
+                    if let Some(heading) = position.heading {
+                        gpx.push_str(&format!("          <heading>{}</heading>\n", heading));
+                    }
+                    if let Some(hdop) = position.position.hdop {
+                        gpx.push_str(&format!("          <hdop>{}</hdop>\n", hdop));
+                    }
+                    if let Some(vdop) = position.position.vdop {
+                        gpx.push_str(&format!("          <vdop>{}</vdop>\n", vdop));
+                    }
+                    if let Some(satellites_used) = position.position.satellites_used {
+                        gpx.push_str(&format!("          <sat>{}</sat>\n", satellites_used));
+                    }
+                    gpx.push_str("        </extensions>\n");
+                }
+                gpx.push_str("      </trkpt>\n");
+            }
+            gpx.push_str("    </trkseg>\n");
+            gpx.push_str("  </trk>\n");
+        }
+
+        gpx.push_str("</gpx>\n");
+        gpx
+    }
+
+    /// Render a time-ordered slice of [`VehiclePosition`] as a GeoJSON
+    /// `FeatureCollection`, one `LineString` feature per vehicle. Since
+    /// GeoJSON has no per-vertex extension mechanism, `speed`/`heading`/
+    /// `timestamps` ride along as parallel arrays in `properties`.
+    pub fn to_geojson(positions: &[VehiclePosition]) -> String {
+        let features: Vec<serde_json::Value> = group_by_vehicle_ordered(positions)
+            .into_iter()
+            .map(|(vehicle_id, points)| {
+                let coordinates: Vec<serde_json::Value> = points
+                    .iter()
+                    .map(|p| match p.position.alt {
+                        Some(alt) => serde_json::json!([p.position.lon, p.position.lat, alt]),
+                        None => serde_json::json!([p.position.lon, p.position.lat]),
+                    })
+                    .collect();
+                let timestamps: Vec<String> = points.iter().map(|p| p.timestamp.to_rfc3339()).collect();
+                let speeds: Vec<Option<f32>> = points.iter().map(|p| p.velocity.as_ref().map(|v| v.speed)).collect();
+                let headings: Vec<Option<f32>> = points.iter().map(|p| p.heading).collect();
+
+                serde_json::json!({
+                    "type": "Feature",
+                    "geometry": {
+                        "type": "LineString",
+                        "coordinates": coordinates,
+                    },
+                    "properties": {
+                        "vehicle_id": vehicle_id.to_string(),
+                        "timestamps": timestamps,
+                        "speeds": speeds,
+                        "headings": headings,
+                    },
+                })
+            })
+            .collect();
+
+        let feature_collection = serde_json::json!({
+            "type": "FeatureCollection",
+            "features": features,
+        });
+
+        serde_json::to_string_pretty(&feature_collection).unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -902,8 +1418,9 @@ mod tests {
             emergency_vehicle: false,
             emergency_type: EmergencyType::NotEmergency,
             priority_level: 0,
+            dynamics: None,
         };
-        
+
         assert!(pos.validate().is_ok());
         
         // Test invalid latitude
@@ -967,4 +1484,394 @@ mod tests {
         let avg = utils::weighted_trust_average(&scores);
         assert!((avg - 0.72).abs() < 0.01);
     }
+
+    fn position_at(lat: f64, lon: f64) -> Position {
+        Position {
+            lat,
+            lon,
+            alt: None,
+            accuracy_horizontal: None,
+            accuracy_vertical: None,
+            hdop: None,
+            vdop: None,
+            tdop: None,
+            satellites_used: None,
+            satellites_visible: None,
+        }
+    }
+
+    #[test]
+    fn test_asserted_distance_to_trust_multiplier_steps_down_by_distance() {
+        let asserted = position_at(40.7128, -74.0060);
+        let close = position_at(40.71281, -74.0060); // roughly 1 m away
+        let middle = position_at(40.71318, -74.0060); // roughly 40 m away
+        let far = position_at(40.7200, -74.0060); // roughly 800 m away
+
+        assert_eq!(utils::asserted_distance_to_trust_multiplier(&close, &asserted, LocationSource::SelfReported), 1.0);
+        assert_eq!(utils::asserted_distance_to_trust_multiplier(&middle, &asserted, LocationSource::SelfReported), 0.25);
+        assert_eq!(utils::asserted_distance_to_trust_multiplier(&far, &asserted, LocationSource::SelfReported), 0.0);
+    }
+
+    #[test]
+    fn test_rsu_and_gps_authoritative_sources_always_trusted() {
+        let asserted = position_at(40.7128, -74.0060);
+        let far = position_at(41.0, -74.0060);
+
+        assert_eq!(utils::asserted_distance_to_trust_multiplier(&far, &asserted, LocationSource::RsuVerified), 1.0);
+        assert_eq!(utils::asserted_distance_to_trust_multiplier(&far, &asserted, LocationSource::GpsAuthoritative), 1.0);
+    }
+
+    #[test]
+    fn test_average_distance_trust_is_ineligible_beyond_threshold() {
+        let asserted = position_at(40.7128, -74.0060);
+        let far = position_at(41.0, -74.0060);
+        let recent = vec![(far.clone(), asserted.clone()), (far, asserted)];
+
+        assert_eq!(utils::average_distance_trust(&recent, 100.0), 0.0);
+    }
+
+    #[test]
+    fn test_average_distance_trust_averages_per_report_multiplier() {
+        let asserted = position_at(40.7128, -74.0060);
+        let close = position_at(40.71281, -74.0060);
+        let middle = position_at(40.71318, -74.0060);
+        let recent = vec![(close, asserted.clone()), (middle, asserted)];
+
+        let avg = utils::average_distance_trust(&recent, 1000.0);
+        assert!((avg - 0.625).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_apply_location_trust_factor_folds_into_overall_score() {
+        let mut trust = TrustMetrics {
+            overall_score: 0.9,
+            behavior_score: 0.9,
+            certificate_score: 0.9,
+            history_score: 0.9,
+            proximity_score: 0.9,
+            sensor_score: 0.9,
+            factors: Vec::new(),
+            flags: Vec::new(),
+            last_update: 0,
+            next_update: 0,
+            anomaly_score: 0.0,
+            anomalies: Vec::new(),
+            anomaly_count: 0,
+        };
+        let asserted = position_at(40.7128, -74.0060);
+        let far = position_at(41.0, -74.0060);
+
+        utils::apply_location_trust_factor(&mut trust, &far, &asserted, LocationSource::SelfReported);
+
+        assert_eq!(trust.factors.len(), 1);
+        assert!(trust.overall_score < 0.9);
+
+        let mut position = VehiclePosition {
+            vehicle_id: Uuid::new_v4(),
+            certificate_id: None,
+            rsu_id: None,
+            position: far,
+            velocity: None,
+            heading: None,
+            speed_accuracy: None,
+            timestamp: Utc::now(),
+            sequence: 1,
+            epoch: 1,
+            metadata: None,
+            sensors: vec![],
+            capabilities: vec![],
+            trust: Some(trust),
+            security: None,
+            network: None,
+            route_waypoints: vec![],
+            emergency_vehicle: false,
+            emergency_type: EmergencyType::NotEmergency,
+            priority_level: 0,
+            dynamics: None,
+        };
+        assert!(!position.is_trusted(0.85));
+
+        position.trust.as_mut().unwrap().overall_score = 0.95;
+        assert!(position.is_trusted(0.9));
+    }
+
+    #[test]
+    fn test_apply_location_trust_updates_vehicle_position_trust_score() {
+        let trust = TrustMetrics {
+            overall_score: 0.9,
+            behavior_score: 0.9,
+            certificate_score: 0.9,
+            history_score: 0.9,
+            proximity_score: 0.9,
+            sensor_score: 0.9,
+            factors: Vec::new(),
+            flags: Vec::new(),
+            last_update: 0,
+            next_update: 0,
+            anomaly_score: 0.0,
+            anomalies: Vec::new(),
+            anomaly_count: 0,
+        };
+        let asserted = position_at(40.7128, -74.0060);
+        let far = position_at(41.0, -74.0060);
+
+        let mut position = VehiclePosition {
+            vehicle_id: Uuid::new_v4(),
+            certificate_id: None,
+            rsu_id: None,
+            position: far,
+            velocity: None,
+            heading: None,
+            speed_accuracy: None,
+            timestamp: Utc::now(),
+            sequence: 1,
+            epoch: 1,
+            metadata: None,
+            sensors: vec![],
+            capabilities: vec![],
+            trust: Some(trust),
+            security: None,
+            network: None,
+            route_waypoints: vec![],
+            emergency_vehicle: false,
+            emergency_type: EmergencyType::NotEmergency,
+            priority_level: 0,
+            dynamics: None,
+        };
+
+        let before = position.trust_score();
+        position.apply_location_trust(&asserted, LocationSource::SelfReported);
+        assert!(position.trust_score() < before);
+        assert_eq!(position.trust.as_ref().unwrap().factors.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_location_trust_is_noop_without_existing_trust_metrics() {
+        let asserted = position_at(40.7128, -74.0060);
+        let far = position_at(41.0, -74.0060);
+
+        let mut position = VehiclePosition {
+            vehicle_id: Uuid::new_v4(),
+            certificate_id: None,
+            rsu_id: None,
+            position: far,
+            velocity: None,
+            heading: None,
+            speed_accuracy: None,
+            timestamp: Utc::now(),
+            sequence: 1,
+            epoch: 1,
+            metadata: None,
+            sensors: vec![],
+            capabilities: vec![],
+            trust: None,
+            security: None,
+            network: None,
+            route_waypoints: vec![],
+            emergency_vehicle: false,
+            emergency_type: EmergencyType::NotEmergency,
+            priority_level: 0,
+            dynamics: None,
+        };
+
+        position.apply_location_trust(&asserted, LocationSource::SelfReported);
+        assert!(position.trust.is_none());
+    }
+
+    #[test]
+    fn test_geo_uri_round_trips() {
+        let position = Position {
+            lat: 40.7128,
+            lon: -74.0060,
+            alt: Some(12.0),
+            accuracy_horizontal: Some(5.0),
+            accuracy_vertical: None,
+            hdop: None,
+            vdop: None,
+            tdop: None,
+            satellites_used: None,
+            satellites_visible: None,
+        };
+
+        let uri = position.to_geo_uri();
+        assert_eq!(uri, "geo:40.7128,-74.006,12;u=5");
+
+        let parsed = Position::from_geo_uri(&uri).unwrap();
+        assert_eq!(parsed.lat, position.lat);
+        assert_eq!(parsed.lon, position.lon);
+        assert_eq!(parsed.alt, position.alt);
+        assert_eq!(parsed.accuracy_horizontal, position.accuracy_horizontal);
+    }
+
+    #[test]
+    fn test_geo_uri_without_altitude_or_uncertainty() {
+        let parsed = Position::from_geo_uri("geo:40.7128,-74.0060").unwrap();
+        assert_eq!(parsed.lat, 40.7128);
+        assert_eq!(parsed.lon, -74.0060);
+        assert_eq!(parsed.alt, None);
+        assert_eq!(parsed.accuracy_horizontal, None);
+    }
+
+    #[test]
+    fn test_geo_uri_rejects_malformed_and_out_of_bounds() {
+        assert!(matches!(Position::from_geo_uri("not-a-geo-uri"), Err(GeoUriError::Malformed(_))));
+        assert!(matches!(Position::from_geo_uri("geo:abc,def"), Err(GeoUriError::Malformed(_))));
+        assert!(matches!(Position::from_geo_uri("geo:200.0,-74.0060"), Err(GeoUriError::OutOfBounds(_))));
+    }
+
+    #[test]
+    fn test_position_try_from_coordinate_tuples() {
+        let position: Position = (40.7128, -74.0060).try_into().unwrap();
+        assert_eq!(position.alt, None);
+
+        let position_with_alt: Position = (40.7128, -74.0060, 12.0).try_into().unwrap();
+        assert_eq!(position_with_alt.alt, Some(12.0));
+
+        let result: Result<Position, _> = (200.0, -74.0060).try_into();
+        assert!(result.is_err());
+    }
+
+    fn sample_trajectory_position(vehicle_id: VehicleId, sequence: u64, lat: f64) -> VehiclePosition {
+        VehiclePosition {
+            vehicle_id,
+            certificate_id: None,
+            rsu_id: None,
+            position: position_at(lat, -74.0060),
+            velocity: Some(Velocity {
+                vx: 1.0,
+                vy: 0.0,
+                vz: 0.0,
+                speed: 12.5,
+                speed_accuracy: None,
+                acceleration: None,
+                deceleration: None,
+            }),
+            heading: Some(90.0),
+            speed_accuracy: None,
+            timestamp: Utc::now(),
+            sequence,
+            epoch: 1,
+            metadata: None,
+            sensors: vec![],
+            capabilities: vec![],
+            trust: None,
+            security: None,
+            network: None,
+            route_waypoints: vec![],
+            emergency_vehicle: false,
+            emergency_type: EmergencyType::NotEmergency,
+            priority_level: 0,
+            dynamics: None,
+        }
+    }
+
+    #[test]
+    fn test_trust_history_decayed_trust_favors_recent_samples() {
+        let mut history = TrustHistory::new();
+        history.record(0, 0.2);
+        history.record(10, 0.9);
+
+        // At epoch 10 with a half-life of 10 epochs, the stale sample is
+        // weighted 0.5 and the fresh one 1.0, so the fresh sample should
+        // dominate the weighted average.
+        let decayed = history.decayed_trust(10, 10.0);
+        assert!(decayed > 0.6, "fresh sample should dominate, got {decayed}");
+
+        // Decay scales every weight by the same factor as time passes, so
+        // the fresh sample keeps exactly twice the stale one's weight and
+        // the result far in the future is still (0.2 + 2 * 0.9) / 3.
+        let far_future = history.decayed_trust(1000, 10.0);
+        assert!((far_future - 2.0 / 3.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_trust_history_prune_drops_stale_samples() {
+        let now = utils::current_epoch();
+        let mut history = TrustHistory::new();
+        history.record(now, 0.8);
+        history.record(now.saturating_sub(1000), 0.1);
+
+        history.prune(10);
+
+        assert_eq!(history.samples().len(), 1);
+        assert_eq!(history.samples()[0].0, now);
+    }
+
+    #[test]
+    fn test_trust_history_empty_decays_to_zero() {
+        let history = TrustHistory::new();
+        assert_eq!(history.decayed_trust(100, 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_to_gpx_groups_by_vehicle_into_separate_tracks() {
+        let vehicle_a = Uuid::new_v4();
+        let vehicle_b = Uuid::new_v4();
+        let positions = vec![
+            sample_trajectory_position(vehicle_a, 1, 40.7128),
+            sample_trajectory_position(vehicle_b, 1, 41.0),
+            sample_trajectory_position(vehicle_a, 2, 40.7200),
+        ];
+
+        let gpx = utils::to_gpx(&positions);
+        assert_eq!(gpx.matches("<trk>").count(), 2);
+        assert_eq!(gpx.matches("<trkpt").count(), 3);
+        assert!(gpx.contains("<speed>12.5</speed>"));
+        assert!(gpx.contains("<heading>90</heading>"));
+    }
+
+    #[test]
+    fn test_to_geojson_produces_linestring_per_vehicle() {
+        let vehicle_a = Uuid::new_v4();
+        let positions =
+            vec![sample_trajectory_position(vehicle_a, 1, 40.7128), sample_trajectory_position(vehicle_a, 2, 40.7200)];
+
+        let geojson = utils::to_geojson(&positions);
+        let parsed: serde_json::Value = serde_json::from_str(&geojson).unwrap();
+        assert_eq!(parsed["type"], "FeatureCollection");
+        assert_eq!(parsed["features"].as_array().unwrap().len(), 1);
+        assert_eq!(parsed["features"][0]["geometry"]["type"], "LineString");
+        assert_eq!(parsed["features"][0]["geometry"]["coordinates"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_body_to_world_round_trips_through_inverse() {
+        let accel = [1.0, -2.0, 9.8];
+        let world = utils::body_to_world(accel, 45.0, 10.0, -5.0);
+        let back = utils::world_to_body(world, 45.0, 10.0, -5.0);
+        for i in 0..3 {
+            assert!((back[i] - accel[i]).abs() < 1e-4, "axis {i}: {back:?} vs {accel:?}");
+        }
+    }
+
+    #[test]
+    fn test_detect_erratic_wheel_speeds() {
+        let wheel = |speed: f32| WheelTelemetry {
+            rotation_speed: speed,
+            suspension_deflection: 0.0,
+            tire_temperature: 60.0,
+            brake_temperature: 80.0,
+            grip_fraction: 0.5,
+        };
+
+        let straight_and_even = Dynamics {
+            front_left: wheel(10.0),
+            front_right: wheel(10.0),
+            rear_left: wheel(10.0),
+            rear_right: wheel(10.0),
+            local_rot_accel: [0.0, 0.0, 0.0],
+            local_accel: [0.0, 0.0, 0.0],
+        };
+        assert_eq!(utils::detect_erratic_wheel_speeds(&straight_and_even, 0.0, 0.0), None);
+
+        let diverging_while_straight = Dynamics {
+            front_left: wheel(5.0),
+            front_right: wheel(15.0),
+            ..straight_and_even
+        };
+        assert_eq!(
+            utils::detect_erratic_wheel_speeds(&diverging_while_straight, 0.0, 0.0),
+            Some(AnomalyType::ErraticMovement)
+        );
+    }
 }
@@ -1,5 +1,7 @@
+use serde::Serialize;
 use thiserror::Error;
 use std::io;
+use std::time::Duration;
 
 /// Main error type for the GeoVAN system
 #[derive(Error, Debug)]
@@ -53,8 +55,14 @@ pub enum GeoVANError {
     Network(#[from] NetworkError),
     
     // Rate limiting errors
-    #[error("Rate limit exceeded: {0}")]
-    RateLimit(String),
+    #[error("Rate limit exceeded: {message}")]
+    RateLimit {
+        message: String,
+        /// Server-provided `Retry-After` hint, when available; callers like
+        /// `utils::resilience::retry` honor this instead of their own
+        /// computed backoff.
+        retry_after: Option<Duration>,
+    },
     
     // Timeout errors
     #[error("Operation timed out: {0}")]
@@ -189,8 +197,11 @@ pub enum AuthorizationError {
     #[error("API key invalid: {0}")]
     ApiKeyInvalid(String),
     
-    #[error("Rate limit exceeded for user: {0}")]
-    UserRateLimitExceeded(String),
+    #[error("Rate limit exceeded for user: {message}")]
+    UserRateLimitExceeded {
+        message: String,
+        retry_after: Option<Duration>,
+    },
 }
 
 /// Validation errors
@@ -394,6 +405,32 @@ pub enum ExternalServiceError {
     NetworkError(String),
 }
 
+/// A single field-level validation failure, surfaced in
+/// [`ProblemDetails::errors`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// An RFC 7807 (`application/problem+json`) representation of a
+/// [`GeoVANError`]. Build one with [`GeoVANError::problem_details`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemDetails {
+    /// A URI identifying the error kind, derived from `error_code()`.
+    pub r#type: String,
+    pub title: &'static str,
+    pub status: u16,
+    pub detail: String,
+    pub instance: String,
+    pub trace_id: String,
+    pub retryable: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+}
+
 /// Result type for GeoVAN operations
 pub type Result<T> = std::result::Result<T, GeoVANError>;
 
@@ -436,19 +473,49 @@ impl GeoVANError {
         matches!(self, Self::Security(_) | Self::Authentication(_) | Self::Authorization(_))
     }
     
-    /// Check if the error is a network-related error
+    /// Check if the error is a transient network-related fault worth
+    /// retrying. Deliberately narrower than "any `Network`/`ExternalService`
+    /// variant": things like bad credentials or an exhausted quota are also
+    /// `ExternalService` errors, but retrying them only repeats the
+    /// failure, so they're excluded.
     pub fn is_network_error(&self) -> bool {
-        matches!(self, Self::Network(_) | Self::ExternalService(_))
+        matches!(
+            self,
+            Self::Network(
+                NetworkError::ConnectionFailed(_)
+                    | NetworkError::ConnectionTimeout(_)
+                    | NetworkError::ConnectionClosed(_)
+                    | NetworkError::NetworkUnreachable(_)
+                    | NetworkError::HostUnreachable(_)
+                    | NetworkError::DnsResolutionFailed(_)
+            ) | Self::ExternalService(
+                ExternalServiceError::ServiceUnavailable(_)
+                    | ExternalServiceError::ServiceTimeout(_)
+                    | ExternalServiceError::RateLimitExceeded(_)
+                    | ExternalServiceError::NetworkError(_)
+            )
+        )
     }
     
     /// Check if the error is recoverable
     pub fn is_recoverable(&self) -> bool {
         matches!(
             self,
-            Self::Timeout(_) | Self::RateLimit(_) | Self::Resource(ResourceError::Timeout(_))
+            Self::Timeout(_) | Self::RateLimit { .. } | Self::Resource(ResourceError::Timeout(_))
         )
     }
-    
+
+    /// A server-provided `Retry-After` hint, when this error carries one.
+    /// `utils::resilience::retry` honors this instead of its own computed
+    /// backoff when present.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimit { retry_after, .. } => *retry_after,
+            Self::Authorization(AuthorizationError::UserRateLimitExceeded { retry_after, .. }) => *retry_after,
+            _ => None,
+        }
+    }
+
     /// Get the error code for API responses
     pub fn error_code(&self) -> &'static str {
         match self {
@@ -464,7 +531,7 @@ impl GeoVANError {
             Self::Protobuf(_) => "PROTOBUF_ERROR",
             Self::Cryptographic(_) => "CRYPTOGRAPHIC_ERROR",
             Self::Network(_) => "NETWORK_ERROR",
-            Self::RateLimit(_) => "RATE_LIMIT_ERROR",
+            Self::RateLimit { .. } => "RATE_LIMIT_ERROR",
             Self::Timeout(_) => "TIMEOUT_ERROR",
             Self::Resource(_) => "RESOURCE_ERROR",
             Self::BusinessLogic(_) => "BUSINESS_LOGIC_ERROR",
@@ -490,7 +557,7 @@ impl GeoVANError {
             Self::Protobuf(_) => 400,
             Self::Cryptographic(_) => 403,
             Self::Network(_) => 503,
-            Self::RateLimit(_) => 429,
+            Self::RateLimit { .. } => 429,
             Self::Timeout(_) => 408,
             Self::Resource(ResourceError::NotFound(_)) => 404,
             Self::Resource(ResourceError::AlreadyExists(_)) => 409,
@@ -508,6 +575,65 @@ impl GeoVANError {
             Self::Unknown => 500,
         }
     }
+
+    /// A short human-readable summary of the error class, suitable for
+    /// `ProblemDetails::title`. Kept distinct from `Display`, which carries
+    /// the specific `detail` for this occurrence.
+    fn title(&self) -> &'static str {
+        match self {
+            Self::Config(_) => "Configuration Error",
+            Self::Database(_) => "Database Error",
+            Self::Redis(_) => "Redis Error",
+            Self::RabbitMQ(_) => "RabbitMQ Error",
+            Self::Security(_) => "Security Error",
+            Self::Authentication(_) => "Authentication Error",
+            Self::Authorization(_) => "Authorization Error",
+            Self::Validation(_) => "Validation Error",
+            Self::Serialization(_) => "Serialization Error",
+            Self::Protobuf(_) => "Protocol Buffer Error",
+            Self::Cryptographic(_) => "Cryptographic Error",
+            Self::Network(_) => "Network Error",
+            Self::RateLimit { .. } => "Rate Limit Exceeded",
+            Self::Timeout(_) => "Operation Timed Out",
+            Self::Resource(_) => "Resource Error",
+            Self::BusinessLogic(_) => "Business Logic Error",
+            Self::ExternalService(_) => "External Service Error",
+            Self::Io(_) => "I/O Error",
+            Self::Generic(_) => "Internal Error",
+            Self::Unknown => "Unknown Error",
+        }
+    }
+
+    /// Field-level validation failures carried by this error, if any,
+    /// rendered for `ProblemDetails::errors`.
+    fn field_errors(&self) -> Vec<FieldError> {
+        match self {
+            Self::Validation(ValidationError::FieldValidation { field, message }) => {
+                vec![FieldError { field: field.clone(), message: message.clone() }]
+            }
+            Self::Validation(ValidationError::InvalidFormat { field, format }) => {
+                vec![FieldError { field: field.clone(), message: format!("invalid format: expected {format}") }]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Render this error as an RFC 7807 `application/problem+json` body.
+    /// `instance` and `trace_id` are freshly generated per call so each
+    /// response can be correlated back to a single occurrence.
+    pub fn problem_details(&self) -> ProblemDetails {
+        let trace_id = uuid::Uuid::new_v4().to_string();
+        ProblemDetails {
+            r#type: format!("https://errors.geovan.dev/{}", self.error_code().to_lowercase()),
+            title: self.title(),
+            status: self.http_status_code(),
+            detail: self.to_string(),
+            instance: format!("urn:geovan:error:{trace_id}"),
+            trace_id,
+            retryable: self.is_recoverable(),
+            errors: self.field_errors(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -543,4 +669,48 @@ mod tests {
         let error = GeoVANError::Generic("test".to_string());
         assert!(!error.is_recoverable());
     }
+
+    #[test]
+    fn test_is_network_error_excludes_non_transient_external_service_faults() {
+        let error = GeoVANError::Network(NetworkError::ConnectionTimeout("slow".to_string()));
+        assert!(error.is_network_error());
+
+        let error = GeoVANError::ExternalService(ExternalServiceError::ServiceUnavailable("down".to_string()));
+        assert!(error.is_network_error());
+
+        let error = GeoVANError::ExternalService(ExternalServiceError::AuthenticationFailed("bad creds".to_string()));
+        assert!(!error.is_network_error());
+
+        let error = GeoVANError::ExternalService(ExternalServiceError::AuthorizationFailed("forbidden".to_string()));
+        assert!(!error.is_network_error());
+
+        let error = GeoVANError::ExternalService(ExternalServiceError::QuotaExceeded("quota".to_string()));
+        assert!(!error.is_network_error());
+    }
+
+    #[test]
+    fn test_problem_details_fields() {
+        let error = GeoVANError::RateLimit { message: "too many requests".to_string(), retry_after: None };
+        let problem = error.problem_details();
+        assert_eq!(problem.status, 429);
+        assert_eq!(problem.r#type, "https://errors.geovan.dev/rate_limit_error");
+        assert!(problem.retryable);
+        assert!(problem.errors.is_empty());
+    }
+
+    #[test]
+    fn test_problem_details_includes_field_errors() {
+        let error = GeoVANError::field_validation("email", "must not be empty");
+        let problem = error.problem_details();
+        assert_eq!(problem.errors.len(), 1);
+        assert_eq!(problem.errors[0].field, "email");
+    }
+
+    #[test]
+    fn test_problem_details_camel_case_serialization() {
+        let error = GeoVANError::Timeout("slow downstream".to_string());
+        let json = serde_json::to_value(error.problem_details()).unwrap();
+        assert!(json.get("traceId").is_some());
+        assert!(json.get("retryable").is_some());
+    }
 }
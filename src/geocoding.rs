@@ -0,0 +1,177 @@
+//! Address geocoding hooks for [`Position`] and route waypoints.
+//!
+//! The [`Geocoder`] trait and [`resolve_route_waypoints`] are dependency-
+//! light so the core type model doesn't have to pull in an HTTP client just
+//! to describe "something that can turn an address into a position." A
+//! concrete network-backed implementation lives in [`http`], gated behind
+//! the `geocoding-http` cargo feature.
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::core::Position;
+
+#[derive(Debug, Clone, Error)]
+pub enum GeocodeError {
+    #[error("no match found for address: {0}")]
+    NotFound(String),
+    #[error("geocoding request failed: {0}")]
+    RequestFailed(String),
+    #[error("geocoder returned an invalid response")]
+    InvalidResponse,
+}
+
+/// Resolves addresses to positions and positions back to addresses.
+/// Object-safe so call sites can hold an `Arc<dyn Geocoder>` and tests can
+/// substitute a stub, mirroring [`crate::utils::dns::Resolver`].
+#[async_trait]
+pub trait Geocoder: Send + Sync {
+    async fn geocode(&self, address: &str) -> Result<Position, GeocodeError>;
+    async fn reverse(&self, pos: &Position) -> Result<String, GeocodeError>;
+}
+
+/// The outcome of resolving a single route waypoint.
+#[derive(Debug, Clone)]
+pub struct WaypointResolution {
+    pub address: String,
+    pub position: Result<Position, GeocodeError>,
+}
+
+/// Resolve human-readable `waypoints` into [`Position`] values via
+/// `geocoder`, ahead of running them through [`crate::core::Validatable`].
+/// Each waypoint is resolved independently: one address failing to
+/// geocode doesn't prevent the others in the batch from resolving, so the
+/// caller gets a full picture of what could and couldn't be resolved.
+pub async fn resolve_route_waypoints(geocoder: &dyn Geocoder, waypoints: &[String]) -> Vec<WaypointResolution> {
+    let mut resolved = Vec::with_capacity(waypoints.len());
+    for address in waypoints {
+        let position = geocoder.geocode(address).await;
+        resolved.push(WaypointResolution { address: address.clone(), position });
+    }
+    resolved
+}
+
+/// Network-backed [`Geocoder`] implementations. Kept behind the
+/// `geocoding-http` feature so embedding GeoVan's core type model doesn't
+/// require an HTTP client stack.
+#[cfg(feature = "geocoding-http")]
+pub mod http {
+    use async_trait::async_trait;
+
+    use super::{GeocodeError, Geocoder};
+    use crate::core::Position;
+
+    /// [`Geocoder`] backed by a configurable HTTP geocoding API (e.g.
+    /// Nominatim-compatible `/search` and `/reverse` endpoints).
+    pub struct HttpGeocoder {
+        client: reqwest::Client,
+        base_url: String,
+    }
+
+    impl HttpGeocoder {
+        pub fn new(base_url: impl Into<String>) -> Self {
+            Self { client: reqwest::Client::new(), base_url: base_url.into() }
+        }
+    }
+
+    #[derive(serde::Deserialize)]
+    struct SearchResult {
+        lat: String,
+        lon: String,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct ReverseResult {
+        display_name: String,
+    }
+
+    #[async_trait]
+    impl Geocoder for HttpGeocoder {
+        async fn geocode(&self, address: &str) -> Result<Position, GeocodeError> {
+            let url = format!("{}/search", self.base_url);
+            let results: Vec<SearchResult> = self
+                .client
+                .get(&url)
+                .query(&[("q", address), ("format", "json")])
+                .send()
+                .await
+                .map_err(|e| GeocodeError::RequestFailed(e.to_string()))?
+                .json()
+                .await
+                .map_err(|e| GeocodeError::RequestFailed(e.to_string()))?;
+
+            let first = results.into_iter().next().ok_or_else(|| GeocodeError::NotFound(address.to_string()))?;
+            let lat: f64 = first.lat.parse().map_err(|_| GeocodeError::InvalidResponse)?;
+            let lon: f64 = first.lon.parse().map_err(|_| GeocodeError::InvalidResponse)?;
+            Position::try_from((lat, lon)).map_err(|_| GeocodeError::InvalidResponse)
+        }
+
+        async fn reverse(&self, pos: &Position) -> Result<String, GeocodeError> {
+            let url = format!("{}/reverse", self.base_url);
+            let result: ReverseResult = self
+                .client
+                .get(&url)
+                .query(&[("lat", pos.lat.to_string()), ("lon", pos.lon.to_string()), ("format".to_string(), "json".to_string())])
+                .send()
+                .await
+                .map_err(|e| GeocodeError::RequestFailed(e.to_string()))?
+                .json()
+                .await
+                .map_err(|e| GeocodeError::RequestFailed(e.to_string()))?;
+
+            Ok(result.display_name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// A stub geocoder for tests that don't want to touch the network.
+    struct StubGeocoder(HashMap<String, Position>);
+
+    #[async_trait]
+    impl Geocoder for StubGeocoder {
+        async fn geocode(&self, address: &str) -> Result<Position, GeocodeError> {
+            self.0.get(address).cloned().ok_or_else(|| GeocodeError::NotFound(address.to_string()))
+        }
+
+        async fn reverse(&self, pos: &Position) -> Result<String, GeocodeError> {
+            self.0
+                .iter()
+                .find(|(_, position)| position.lat == pos.lat && position.lon == pos.lon)
+                .map(|(address, _)| address.clone())
+                .ok_or(GeocodeError::InvalidResponse)
+        }
+    }
+
+    fn stub_position(lat: f64, lon: f64) -> Position {
+        Position::try_from((lat, lon)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_resolve_route_waypoints_isolates_per_waypoint_errors() {
+        let mut known = HashMap::new();
+        known.insert("City Hall".to_string(), stub_position(40.7128, -74.0060));
+        let geocoder = StubGeocoder(known);
+
+        let waypoints = vec!["City Hall".to_string(), "Nonexistent Place".to_string()];
+        let resolved = resolve_route_waypoints(&geocoder, &waypoints).await;
+
+        assert_eq!(resolved.len(), 2);
+        assert!(resolved[0].position.is_ok());
+        assert!(matches!(resolved[1].position, Err(GeocodeError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reverse_geocode_finds_matching_address() {
+        let mut known = HashMap::new();
+        known.insert("City Hall".to_string(), stub_position(40.7128, -74.0060));
+        let geocoder = StubGeocoder(known);
+
+        let address = geocoder.reverse(&stub_position(40.7128, -74.0060)).await.unwrap();
+        assert_eq!(address, "City Hall");
+    }
+}
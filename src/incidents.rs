@@ -0,0 +1,248 @@
+//! Traffic incident ingestion and correlation with observed vehicle
+//! clusters.
+//!
+//! [`VehicleCluster`] already classifies jams, accident scenes, and
+//! construction zones from vehicle telemetry alone, but nothing ties that
+//! classification back to an authoritative traffic-incident feed. This
+//! module adds [`TrafficIncident`] as the normalized shape such feeds are
+//! ingested into (via [`IncidentSource`]), and [`correlate`], which matches
+//! clusters to incidents by proximity, upgrades the cluster's
+//! [`ClusterType`] when an incident corroborates it, and emits a
+//! [`AlertType::Traffic`] [`Alert`] scaled by how dense the corroborating
+//! cluster is.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::core::{self, Alert, AlertSeverity, AlertType, ClusterType, Position, VehicleCluster};
+
+#[derive(Debug, Error)]
+pub enum IncidentError {
+    #[error("failed to fetch incidents from {feed}: {message}")]
+    Fetch { feed: String, message: String },
+    #[error("failed to parse incident feed payload: {0}")]
+    Parse(String),
+}
+
+/// Category of a traffic-authority incident, as reported by the upstream feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IncidentCategory {
+    Accident = 0,
+    RoadWorks = 1,
+    VehicleBreakdown = 2,
+    RoadBlock = 3,
+    HeavyTraffic = 4,
+    Weather = 5,
+}
+
+/// A normalized traffic incident, regardless of which upstream feed it came from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficIncident {
+    pub incident_id: String,
+    pub category: IncidentCategory,
+    pub location: Position,
+    /// Radius of the affected area, in meters
+    pub radius: f32,
+    pub message: String,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+}
+
+impl TrafficIncident {
+    /// Whether this incident's validity window covers `when`.
+    pub fn is_active_at(&self, when: DateTime<Utc>) -> bool {
+        when >= self.valid_from && when <= self.valid_until
+    }
+}
+
+/// A source of traffic-authority incidents, normalized into [`TrafficIncident`].
+/// Implementations poll whatever upstream format (XML, GeoJSON, proprietary
+/// REST) the traffic authority exposes and translate it into this crate's
+/// representation.
+#[async_trait]
+pub trait IncidentSource: Send + Sync {
+    async fn poll(&self) -> Result<Vec<TrafficIncident>, IncidentError>;
+}
+
+/// Escalation threshold: clusters denser than this (vehicles per unit area,
+/// same scale as [`VehicleCluster::density`]) bump the emitted alert's
+/// severity by one level.
+const DENSITY_ESCALATION_THRESHOLD: f32 = 0.7;
+
+fn base_severity(category: IncidentCategory) -> AlertSeverity {
+    match category {
+        IncidentCategory::Accident => AlertSeverity::Critical,
+        IncidentCategory::RoadBlock => AlertSeverity::High,
+        IncidentCategory::VehicleBreakdown => AlertSeverity::Medium,
+        IncidentCategory::HeavyTraffic => AlertSeverity::Medium,
+        IncidentCategory::Weather => AlertSeverity::Medium,
+        IncidentCategory::RoadWorks => AlertSeverity::Low,
+    }
+}
+
+fn escalate(severity: AlertSeverity) -> AlertSeverity {
+    match severity {
+        AlertSeverity::Info => AlertSeverity::Low,
+        AlertSeverity::Low => AlertSeverity::Medium,
+        AlertSeverity::Medium => AlertSeverity::High,
+        AlertSeverity::High => AlertSeverity::Critical,
+        AlertSeverity::Critical => AlertSeverity::Emergency,
+        AlertSeverity::Emergency => AlertSeverity::Emergency,
+    }
+}
+
+fn severity_for(density: f32, category: IncidentCategory) -> AlertSeverity {
+    let severity = base_severity(category);
+    if density > DENSITY_ESCALATION_THRESHOLD {
+        escalate(severity)
+    } else {
+        severity
+    }
+}
+
+fn upgraded_cluster_type(category: IncidentCategory, current: ClusterType) -> ClusterType {
+    match category {
+        IncidentCategory::Accident => ClusterType::AccidentScene,
+        IncidentCategory::RoadWorks => ClusterType::Construction,
+        _ => current,
+    }
+}
+
+/// Match each cluster against every currently-active incident whose
+/// affected area overlaps the cluster's footprint, upgrading the cluster's
+/// `cluster_type` when an incident corroborates it and emitting a
+/// [`AlertType::Traffic`] alert for every match.
+///
+/// Takes `clusters` by mutable reference (rather than the read-only slice
+/// the upgrade behavior might suggest) because a cluster's `cluster_type`
+/// is only meaningful if the upgrade is actually observable by the caller.
+pub fn correlate(clusters: &mut [VehicleCluster], incidents: &[TrafficIncident]) -> Vec<Alert> {
+    let now = Utc::now();
+    let mut alerts = Vec::new();
+
+    for cluster in clusters.iter_mut() {
+        for incident in incidents {
+            if !incident.is_active_at(now) {
+                continue;
+            }
+
+            let distance = core::utils::calculate_distance(&cluster.center, &incident.location);
+            let combined_radius = cluster.radius as f64 + incident.radius as f64;
+            if distance > combined_radius {
+                continue;
+            }
+
+            cluster.cluster_type = upgraded_cluster_type(incident.category, cluster.cluster_type);
+
+            alerts.push(Alert {
+                alert_id: format!("{}-{:?}", incident.incident_id, incident.category),
+                alert_type: AlertType::Traffic,
+                severity: severity_for(cluster.density, incident.category),
+                title: format!("{:?} corroborated by a vehicle cluster", incident.category),
+                description: incident.message.clone(),
+                vehicle_id: None,
+                location: Some(cluster.center.clone()),
+                timestamp: now,
+                tags: vec!["incident".to_string(), "correlated".to_string()],
+                acknowledged: false,
+                acknowledged_by: None,
+                acknowledged_at: None,
+            });
+        }
+    }
+
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(lat: f64, lon: f64) -> Position {
+        Position {
+            lat,
+            lon,
+            alt: None,
+            accuracy_horizontal: None,
+            accuracy_vertical: None,
+            hdop: None,
+            vdop: None,
+            tdop: None,
+            satellites_used: None,
+            satellites_visible: None,
+        }
+    }
+
+    fn cluster(center: Position, density: f32, cluster_type: ClusterType) -> VehicleCluster {
+        VehicleCluster {
+            center,
+            count: 6,
+            avg_speed: 4.0,
+            density,
+            vehicle_ids: (0..6).map(|_| core::VehicleId::new_v4()).collect(),
+            cluster_type,
+            radius: 50.0,
+            formation_time: 0,
+            last_update: 0,
+        }
+    }
+
+    fn incident(category: IncidentCategory, location: Position) -> TrafficIncident {
+        TrafficIncident {
+            incident_id: "incident-1".to_string(),
+            category,
+            location,
+            radius: 50.0,
+            message: "reported by traffic authority".to_string(),
+            valid_from: Utc::now() - chrono::Duration::minutes(10),
+            valid_until: Utc::now() + chrono::Duration::minutes(10),
+        }
+    }
+
+    #[test]
+    fn test_overlapping_incident_upgrades_cluster_and_emits_alert() {
+        let mut clusters = vec![cluster(position(40.7128, -74.0060), 0.5, ClusterType::TrafficJam)];
+        let incidents = vec![incident(IncidentCategory::Accident, position(40.7129, -74.0061))];
+
+        let alerts = correlate(&mut clusters, &incidents);
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].alert_type, AlertType::Traffic);
+        assert_eq!(clusters[0].cluster_type, ClusterType::AccidentScene);
+    }
+
+    #[test]
+    fn test_distant_incident_does_not_correlate() {
+        let mut clusters = vec![cluster(position(40.7128, -74.0060), 0.5, ClusterType::TrafficJam)];
+        let incidents = vec![incident(IncidentCategory::Accident, position(34.0522, -118.2437))];
+
+        let alerts = correlate(&mut clusters, &incidents);
+
+        assert!(alerts.is_empty());
+        assert_eq!(clusters[0].cluster_type, ClusterType::TrafficJam);
+    }
+
+    #[test]
+    fn test_dense_cluster_escalates_severity() {
+        let mut sparse = vec![cluster(position(1.0, 1.0), 0.1, ClusterType::TrafficJam)];
+        let mut dense = vec![cluster(position(1.0, 1.0), 0.9, ClusterType::TrafficJam)];
+        let incidents = vec![incident(IncidentCategory::HeavyTraffic, position(1.0, 1.0))];
+
+        let sparse_alerts = correlate(&mut sparse, &incidents);
+        let dense_alerts = correlate(&mut dense, &incidents);
+
+        assert!(dense_alerts[0].severity as u8 > sparse_alerts[0].severity as u8);
+    }
+
+    #[test]
+    fn test_expired_incident_is_ignored() {
+        let mut clusters = vec![cluster(position(1.0, 1.0), 0.5, ClusterType::TrafficJam)];
+        let mut expired = incident(IncidentCategory::Accident, position(1.0, 1.0));
+        expired.valid_until = Utc::now() - chrono::Duration::minutes(1);
+
+        let alerts = correlate(&mut clusters, &[expired]);
+        assert!(alerts.is_empty());
+    }
+}
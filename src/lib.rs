@@ -81,8 +81,21 @@ pub mod security;
 pub mod services;
 pub mod analytics;
 pub mod api;
+pub mod archive;
+pub mod certgen;
+pub mod codec;
 pub mod error;
+pub mod geocoding;
+pub mod incidents;
+pub mod log;
 pub mod metrics;
+pub mod pool;
+pub mod ratelimiting;
+pub mod secrets;
+pub mod shutdown;
+pub mod store;
+#[cfg(feature = "syslog")]
+pub mod syslog;
 pub mod utils;
 
 // Re-export commonly used types
@@ -98,36 +111,127 @@ pub use error::{GeoVANError, Result};
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const NAME: &str = env!("CARGO_PKG_NAME");
 
-/// Initialize the GeoVAN system with logging and configuration
-pub async fn init() -> Result<Config> {
+/// Non-blocking writer guards that must be held for the process lifetime;
+/// dropping one stops its sink from flushing.
+pub struct LogGuards {
+    _stdout: tracing_appender::non_blocking::WorkerGuard,
+    _file: Option<tracing_appender::non_blocking::WorkerGuard>,
+}
+
+/// Initialize the GeoVAN system with logging and configuration.
+///
+/// Returns the loaded `Config` alongside [`LogGuards`], which the caller
+/// must keep alive (e.g. bind to a variable in `main`) for log sinks to
+/// keep flushing for the life of the process.
+pub async fn init() -> Result<(Config, LogGuards)> {
     // Load environment variables
     dotenv::dotenv().ok();
-    
-    // Initialize logging
-    tracing_subscriber::fmt()
-        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
-        .init();
-    
-    // Load configuration
+
+    // Load configuration before touching logging, since the subscriber is
+    // built from it.
     let config = Config::load()?;
-    
+    let guards = init_logging(&config)?;
+
+    // Opt-in (see `SecurityConfig::auto_generate_certificates`): bootstrap
+    // mTLS material for deployments that don't bring their own certs.
+    if config.security.auto_generate_certificates {
+        config.ensure_certificates()?;
+    }
+
     tracing::info!("GeoVAN {} initialized", VERSION);
     tracing::info!("Configuration loaded from: {}", config.config_path);
-    
-    Ok(config)
+
+    Ok((config, guards))
 }
 
-/// Graceful shutdown handler
-pub async fn shutdown() {
+/// Build a layered `tracing-subscriber` from `config.logging`: stdout is
+/// always on, a rotating file appender is added when `logging.directory`
+/// is set, and a syslog sink is added when both `logging.syslog` is set
+/// and the crate is built with the `syslog` feature.
+fn init_logging(config: &config::Config) -> Result<LogGuards> {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let mut filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(&config.app.log_level));
+    for (module, level) in &config.logging.module_filters {
+        filter = filter.add_directive(format!("{module}={level}").parse().map_err(|e| {
+            error::GeoVANError::generic(format!("invalid log directive for {module}: {e}"))
+        })?);
+    }
+
+    let (stdout_writer, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
+    let stdout_layer = build_fmt_layer(config.logging.format, stdout_writer);
+
+    let (file_layer, file_guard) = match &config.logging.directory {
+        Some(dir) => {
+            let rotation = match config.logging.rotation {
+                config::LogRotation::Daily => tracing_appender::rolling::Rotation::DAILY,
+                config::LogRotation::Hourly => tracing_appender::rolling::Rotation::HOURLY,
+                config::LogRotation::Never => tracing_appender::rolling::Rotation::NEVER,
+            };
+            let appender = tracing_appender::rolling::RollingFileAppender::new(rotation, dir, "geovan.log");
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            (Some(build_fmt_layer(config.logging.format, writer)), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    let registry = tracing_subscriber::registry().with(filter).with(stdout_layer).with(file_layer);
+
+    #[cfg(feature = "syslog")]
+    {
+        if let Some(syslog_config) = &config.logging.syslog {
+            let syslog_layer = syslog::build_layer(syslog_config)
+                .map_err(|e| error::GeoVANError::generic(format!("failed to initialize syslog sink: {e}")))?;
+            registry.with(syslog_layer).init();
+            return Ok(LogGuards { _stdout: stdout_guard, _file: file_guard });
+        }
+    }
+
+    registry.init();
+    Ok(LogGuards { _stdout: stdout_guard, _file: file_guard })
+}
+
+/// Build an `fmt` layer in either JSON or human-readable form, sharing the
+/// given writer across both formats so callers don't need to branch.
+///
+/// Generic over the subscriber it's composed into (rather than fixed to
+/// `Registry`) because `init_logging` layers this on top of `.with(filter)`,
+/// whose concrete type is `Layered<EnvFilter, Registry>`, not `Registry`
+/// itself.
+fn build_fmt_layer<S, W>(
+    format: config::LogFormat,
+    writer: W,
+) -> Box<dyn tracing_subscriber::Layer<S> + Send + Sync>
+where
+    S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+    W: for<'writer> tracing_subscriber::fmt::MakeWriter<'writer> + Send + Sync + 'static,
+{
+    use tracing_subscriber::fmt;
+
+    match format {
+        config::LogFormat::Json => Box::new(fmt::layer().json().with_writer(writer)),
+        config::LogFormat::Pretty => Box::new(fmt::layer().pretty().with_writer(writer)),
+    }
+}
+
+/// Broadcast the shutdown signal on `coordinator`, wait up to `deadline`
+/// for every registered service to drain, then force-close `pools`.
+/// Returns `Err(ResourceError)` instead of panicking if any service misses
+/// the deadline, so an orchestrator sees a clean non-zero exit rather than
+/// a panic unwinding through dangling connections.
+pub async fn shutdown(
+    coordinator: &shutdown::ShutdownCoordinator,
+    pools: Option<&pool::Pools>,
+    deadline: std::time::Duration,
+) -> Result<()> {
     tracing::info!("Shutting down GeoVAN...");
-    
-    // Perform cleanup tasks here
-    // - Close database connections
-    // - Stop background tasks
-    // - Flush logs
-    // - Close file handles
-    
+
+    coordinator.shutdown(deadline, pools).await?;
+
     tracing::info!("GeoVAN shutdown complete");
+    Ok(())
 }
 
 #[cfg(test)]
@@ -0,0 +1,741 @@
+//! Schema-evolvable append-only logging for fleet replay and post-incident
+//! analysis.
+//!
+//! Unlike [`crate::codec`], whose fixed layout optimizes for on-wire
+//! compactness between two sides running the same build, this module is
+//! built for durability across software versions: every record's fields
+//! carry an explicit numeric index inside a length-prefixed envelope, so a
+//! reader can skip fields it doesn't recognize (written by a newer writer)
+//! and a field absent from an older entry simply decodes to its default.
+//! Each envelope is itself framed with an outer length prefix so
+//! [`LogReader`] can stop cleanly on a truncated trailing record instead of
+//! erroring out.
+//!
+//! Nested, stable substructures (e.g. [`Position`], [`VehicleMetadata`])
+//! are encoded with the fixed-layout helpers from [`crate::codec`] rather
+//! than re-deriving numbered fields for every level — the numbering only
+//! needs to cover the fields that are likely to change independently of
+//! the record they belong to.
+
+use std::io::{self, Read, Write};
+
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::codec::CodecError;
+use crate::core::{
+    Alert, AlertSeverity, AlertType, AnomalyType, EmergencyType, Position, SensorReading, SensorType, TrustFactor,
+    TrustMetrics, VehicleId, VehiclePosition,
+};
+
+#[derive(Debug, Error)]
+pub enum LogError {
+    #[error("I/O error reading or writing the log: {0}")]
+    Io(#[from] io::Error),
+    #[error("unknown union tag {0} in log envelope")]
+    InvalidUnionTag(u8),
+    #[error("truncated log envelope")]
+    Truncated,
+    #[error("invalid timestamp encoding in log envelope")]
+    InvalidTimestamp,
+    #[error("invalid UTF-8 in log field: {0}")]
+    InvalidUtf8(String),
+    #[error("invalid nested encoding in log field: {0}")]
+    Codec(#[from] CodecError),
+    #[error("log envelope length {found} exceeds maximum of {max}")]
+    EnvelopeTooLarge { found: u32, max: u32 },
+}
+
+/// Sanity cap on a single envelope's length prefix. A corrupted or
+/// truncated length (e.g. reading mid-write garbage as the prefix) would
+/// otherwise trigger an allocation sized by whatever garbage `u32` was
+/// read, instead of the clean truncation this module's docs promise.
+const MAX_ENVELOPE_LEN: u32 = 64 * 1024 * 1024;
+
+/// One of the record kinds this log format knows how to frame.
+#[derive(Debug, Clone)]
+pub enum LogRecord {
+    VehiclePosition(VehiclePosition),
+    SensorReading(SensorReading),
+    Alert(Alert),
+    TrustMetrics(TrustMetrics),
+}
+
+const TAG_VEHICLE_POSITION: u8 = 1;
+const TAG_SENSOR_READING: u8 = 2;
+const TAG_ALERT: u8 = 3;
+const TAG_TRUST_METRICS: u8 = 4;
+
+fn write_field(buf: &mut Vec<u8>, index: u16, payload: Vec<u8>) {
+    buf.extend_from_slice(&index.to_le_bytes());
+    buf.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    buf.extend_from_slice(&payload);
+}
+
+/// Walk every `(index, len, payload)` field in a record body, calling `f`
+/// for each. Unknown indices are the caller's responsibility to ignore.
+fn for_each_field<'a>(buf: &'a [u8], mut f: impl FnMut(u16, &'a [u8]) -> Result<(), LogError>) -> Result<(), LogError> {
+    let mut pos = 0;
+    while pos < buf.len() {
+        if pos + 6 > buf.len() {
+            return Err(LogError::Truncated);
+        }
+        let index = u16::from_le_bytes(buf[pos..pos + 2].try_into().unwrap());
+        let len = u32::from_le_bytes(buf[pos + 2..pos + 6].try_into().unwrap()) as usize;
+        pos += 6;
+        if pos + len > buf.len() {
+            return Err(LogError::Truncated);
+        }
+        let payload = &buf[pos..pos + len];
+        pos += len;
+        f(index, payload)?;
+    }
+    Ok(())
+}
+
+fn decode_string(payload: &[u8]) -> Result<String, LogError> {
+    String::from_utf8(payload.to_vec()).map_err(|e| LogError::InvalidUtf8(e.to_string()))
+}
+
+fn decode_f32(payload: &[u8]) -> Result<f32, LogError> {
+    Ok(f32::from_le_bytes(payload.try_into().map_err(|_| LogError::Truncated)?))
+}
+
+fn decode_u32(payload: &[u8]) -> Result<u32, LogError> {
+    Ok(u32::from_le_bytes(payload.try_into().map_err(|_| LogError::Truncated)?))
+}
+
+fn decode_u64(payload: &[u8]) -> Result<u64, LogError> {
+    Ok(u64::from_le_bytes(payload.try_into().map_err(|_| LogError::Truncated)?))
+}
+
+fn decode_timestamp_field(payload: &[u8]) -> Result<DateTime<Utc>, LogError> {
+    let nanos = i64::from_le_bytes(payload.try_into().map_err(|_| LogError::Truncated)?);
+    DateTime::from_timestamp(nanos.div_euclid(1_000_000_000), nanos.rem_euclid(1_000_000_000) as u32)
+        .ok_or(LogError::InvalidTimestamp)
+}
+
+fn default_position() -> Position {
+    Position {
+        lat: 0.0,
+        lon: 0.0,
+        alt: None,
+        accuracy_horizontal: None,
+        accuracy_vertical: None,
+        hdop: None,
+        vdop: None,
+        tdop: None,
+        satellites_used: None,
+        satellites_visible: None,
+    }
+}
+
+fn default_timestamp() -> DateTime<Utc> {
+    DateTime::from_timestamp(0, 0).expect("unix epoch is a valid timestamp")
+}
+
+fn encode_vehicle_position_fields(v: &VehiclePosition) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_field(&mut buf, 1, v.vehicle_id.as_bytes().to_vec());
+    if let Some(cert) = &v.certificate_id {
+        write_field(&mut buf, 2, cert.clone().into_bytes());
+    }
+    if let Some(rsu) = &v.rsu_id {
+        write_field(&mut buf, 3, rsu.clone().into_bytes());
+    }
+    write_field(&mut buf, 4, {
+        let mut p = Vec::new();
+        crate::codec::write_position(&mut p, &v.position);
+        p
+    });
+    if let Some(velocity) = &v.velocity {
+        write_field(&mut buf, 5, {
+            let mut p = Vec::new();
+            crate::codec::write_velocity(&mut p, velocity);
+            p
+        });
+    }
+    if let Some(heading) = v.heading {
+        write_field(&mut buf, 6, heading.to_le_bytes().to_vec());
+    }
+    if let Some(speed_accuracy) = v.speed_accuracy {
+        write_field(&mut buf, 7, speed_accuracy.to_le_bytes().to_vec());
+    }
+    write_field(&mut buf, 8, v.timestamp.timestamp_nanos_opt().unwrap_or(0).to_le_bytes().to_vec());
+    write_field(&mut buf, 9, v.sequence.to_le_bytes().to_vec());
+    write_field(&mut buf, 10, v.epoch.to_le_bytes().to_vec());
+    if let Some(metadata) = &v.metadata {
+        write_field(&mut buf, 11, {
+            let mut p = Vec::new();
+            crate::codec::write_vehicle_metadata(&mut p, metadata);
+            p
+        });
+    }
+    if !v.sensors.is_empty() {
+        write_field(&mut buf, 12, {
+            let mut p = Vec::new();
+            crate::codec::write_vec(&mut p, &v.sensors, crate::codec::write_sensor_reading);
+            p
+        });
+    }
+    if !v.capabilities.is_empty() {
+        write_field(&mut buf, 13, {
+            let mut p = Vec::new();
+            crate::codec::write_vec(&mut p, &v.capabilities, crate::codec::write_capability);
+            p
+        });
+    }
+    if let Some(trust) = &v.trust {
+        write_field(&mut buf, 14, {
+            let mut p = Vec::new();
+            crate::codec::write_trust_metrics(&mut p, trust);
+            p
+        });
+    }
+    if let Some(security) = &v.security {
+        write_field(&mut buf, 15, {
+            let mut p = Vec::new();
+            crate::codec::write_security_flags(&mut p, security);
+            p
+        });
+    }
+    if let Some(network) = &v.network {
+        write_field(&mut buf, 16, {
+            let mut p = Vec::new();
+            crate::codec::write_network_info(&mut p, network);
+            p
+        });
+    }
+    if !v.route_waypoints.is_empty() {
+        write_field(&mut buf, 17, {
+            let mut p = Vec::new();
+            crate::codec::write_vec(&mut p, &v.route_waypoints, |b, s| crate::codec::write_string(b, s));
+            p
+        });
+    }
+    write_field(&mut buf, 18, vec![v.emergency_vehicle as u8]);
+    write_field(&mut buf, 19, vec![v.emergency_type.to_wire_u8()]);
+    write_field(&mut buf, 20, v.priority_level.to_le_bytes().to_vec());
+    if let Some(dynamics) = &v.dynamics {
+        write_field(&mut buf, 21, {
+            let mut p = Vec::new();
+            crate::codec::write_dynamics(&mut p, dynamics);
+            p
+        });
+    }
+    buf
+}
+
+fn decode_vehicle_position_fields(buf: &[u8]) -> Result<VehiclePosition, LogError> {
+    let mut vehicle_id = VehicleId::nil();
+    let mut certificate_id = None;
+    let mut rsu_id = None;
+    let mut position = default_position();
+    let mut velocity = None;
+    let mut heading = None;
+    let mut speed_accuracy = None;
+    let mut timestamp = default_timestamp();
+    let mut sequence = 0u64;
+    let mut epoch = 0u64;
+    let mut metadata = None;
+    let mut sensors = Vec::new();
+    let mut capabilities = Vec::new();
+    let mut trust = None;
+    let mut security = None;
+    let mut network = None;
+    let mut route_waypoints = Vec::new();
+    let mut emergency_vehicle = false;
+    let mut emergency_type = EmergencyType::NotEmergency;
+    let mut priority_level = 0u32;
+    let mut dynamics = None;
+
+    for_each_field(buf, |index, payload| {
+        match index {
+            1 => vehicle_id = VehicleId::from_bytes(payload.try_into().map_err(|_| LogError::Truncated)?),
+            2 => certificate_id = Some(decode_string(payload)?),
+            3 => rsu_id = Some(decode_string(payload)?),
+            4 => position = crate::codec::read_position(&mut crate::codec::Reader::new(payload))?,
+            5 => velocity = Some(crate::codec::read_velocity(&mut crate::codec::Reader::new(payload))?),
+            6 => heading = Some(decode_f32(payload)?),
+            7 => speed_accuracy = Some(decode_f32(payload)?),
+            8 => timestamp = decode_timestamp_field(payload)?,
+            9 => sequence = decode_u64(payload)?,
+            10 => epoch = decode_u64(payload)?,
+            11 => metadata = Some(crate::codec::read_vehicle_metadata(&mut crate::codec::Reader::new(payload))?),
+            12 => sensors = crate::codec::Reader::new(payload).vec(crate::codec::read_sensor_reading)?,
+            13 => capabilities = crate::codec::Reader::new(payload).vec(crate::codec::read_capability)?,
+            14 => trust = Some(crate::codec::read_trust_metrics(&mut crate::codec::Reader::new(payload))?),
+            15 => security = Some(crate::codec::read_security_flags(&mut crate::codec::Reader::new(payload))?),
+            16 => network = Some(crate::codec::read_network_info(&mut crate::codec::Reader::new(payload))?),
+            17 => route_waypoints = crate::codec::Reader::new(payload).vec(|r| r.string())?,
+            18 => emergency_vehicle = payload.first().copied().unwrap_or(0) != 0,
+            19 => emergency_type = EmergencyType::from_wire_u8(payload.first().copied().unwrap_or(0))?,
+            20 => priority_level = decode_u32(payload)?,
+            21 => dynamics = Some(crate::codec::read_dynamics(&mut crate::codec::Reader::new(payload))?),
+            _ => {}
+        }
+        Ok(())
+    })?;
+
+    Ok(VehiclePosition {
+        vehicle_id,
+        certificate_id,
+        rsu_id,
+        position,
+        velocity,
+        heading,
+        speed_accuracy,
+        timestamp,
+        sequence,
+        epoch,
+        metadata,
+        sensors,
+        capabilities,
+        trust,
+        security,
+        network,
+        route_waypoints,
+        emergency_vehicle,
+        emergency_type,
+        priority_level,
+        dynamics,
+    })
+}
+
+fn encode_sensor_reading_fields(s: &SensorReading) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_field(&mut buf, 1, vec![s.sensor_type.to_wire_u8()]);
+    write_field(&mut buf, 2, s.value.to_le_bytes().to_vec());
+    if let Some(accuracy) = s.accuracy {
+        write_field(&mut buf, 3, accuracy.to_le_bytes().to_vec());
+    }
+    write_field(&mut buf, 4, s.timestamp.timestamp_nanos_opt().unwrap_or(0).to_le_bytes().to_vec());
+    write_field(&mut buf, 5, s.unit.clone().into_bytes());
+    if let Some(min_value) = s.min_value {
+        write_field(&mut buf, 6, min_value.to_le_bytes().to_vec());
+    }
+    if let Some(max_value) = s.max_value {
+        write_field(&mut buf, 7, max_value.to_le_bytes().to_vec());
+    }
+    write_field(&mut buf, 8, vec![s.is_calibrated as u8]);
+    if let Some(calibration_date) = s.calibration_date {
+        write_field(&mut buf, 9, calibration_date.to_le_bytes().to_vec());
+    }
+    buf
+}
+
+fn decode_sensor_reading_fields(buf: &[u8]) -> Result<SensorReading, LogError> {
+    let mut sensor_type = SensorType::Gps;
+    let mut value = 0.0f32;
+    let mut accuracy = None;
+    let mut timestamp = default_timestamp();
+    let mut unit = String::new();
+    let mut min_value = None;
+    let mut max_value = None;
+    let mut is_calibrated = false;
+    let mut calibration_date = None;
+
+    for_each_field(buf, |index, payload| {
+        match index {
+            1 => sensor_type = SensorType::from_wire_u8(payload.first().copied().unwrap_or(0))?,
+            2 => value = decode_f32(payload)?,
+            3 => accuracy = Some(decode_f32(payload)?),
+            4 => timestamp = decode_timestamp_field(payload)?,
+            5 => unit = decode_string(payload)?,
+            6 => min_value = Some(decode_f32(payload)?),
+            7 => max_value = Some(decode_f32(payload)?),
+            8 => is_calibrated = payload.first().copied().unwrap_or(0) != 0,
+            9 => calibration_date = Some(decode_u64(payload)?),
+            _ => {}
+        }
+        Ok(())
+    })?;
+
+    Ok(SensorReading { sensor_type, value, accuracy, timestamp, unit, min_value, max_value, is_calibrated, calibration_date })
+}
+
+fn encode_alert_fields(a: &Alert) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_field(&mut buf, 1, a.alert_id.clone().into_bytes());
+    write_field(&mut buf, 2, vec![a.alert_type.to_wire_u8()]);
+    write_field(&mut buf, 3, vec![a.severity.to_wire_u8()]);
+    write_field(&mut buf, 4, a.title.clone().into_bytes());
+    write_field(&mut buf, 5, a.description.clone().into_bytes());
+    if let Some(vehicle_id) = &a.vehicle_id {
+        write_field(&mut buf, 6, vehicle_id.as_bytes().to_vec());
+    }
+    if let Some(location) = &a.location {
+        write_field(&mut buf, 7, {
+            let mut p = Vec::new();
+            crate::codec::write_position(&mut p, location);
+            p
+        });
+    }
+    write_field(&mut buf, 8, a.timestamp.timestamp_nanos_opt().unwrap_or(0).to_le_bytes().to_vec());
+    if !a.tags.is_empty() {
+        write_field(&mut buf, 9, {
+            let mut p = Vec::new();
+            crate::codec::write_vec(&mut p, &a.tags, |b, s| crate::codec::write_string(b, s));
+            p
+        });
+    }
+    write_field(&mut buf, 10, vec![a.acknowledged as u8]);
+    if let Some(acknowledged_by) = &a.acknowledged_by {
+        write_field(&mut buf, 11, acknowledged_by.clone().into_bytes());
+    }
+    if let Some(acknowledged_at) = a.acknowledged_at {
+        write_field(&mut buf, 12, acknowledged_at.to_le_bytes().to_vec());
+    }
+    buf
+}
+
+fn decode_alert_fields(buf: &[u8]) -> Result<Alert, LogError> {
+    let mut alert_id = String::new();
+    let mut alert_type = AlertType::Security;
+    let mut severity = AlertSeverity::Info;
+    let mut title = String::new();
+    let mut description = String::new();
+    let mut vehicle_id = None;
+    let mut location = None;
+    let mut timestamp = default_timestamp();
+    let mut tags = Vec::new();
+    let mut acknowledged = false;
+    let mut acknowledged_by = None;
+    let mut acknowledged_at = None;
+
+    for_each_field(buf, |index, payload| {
+        match index {
+            1 => alert_id = decode_string(payload)?,
+            2 => alert_type = AlertType::from_wire_u8(payload.first().copied().unwrap_or(0))?,
+            3 => severity = AlertSeverity::from_wire_u8(payload.first().copied().unwrap_or(0))?,
+            4 => title = decode_string(payload)?,
+            5 => description = decode_string(payload)?,
+            6 => vehicle_id = Some(VehicleId::from_bytes(payload.try_into().map_err(|_| LogError::Truncated)?)),
+            7 => location = Some(crate::codec::read_position(&mut crate::codec::Reader::new(payload))?),
+            8 => timestamp = decode_timestamp_field(payload)?,
+            9 => tags = crate::codec::Reader::new(payload).vec(|r| r.string())?,
+            10 => acknowledged = payload.first().copied().unwrap_or(0) != 0,
+            11 => acknowledged_by = Some(decode_string(payload)?),
+            12 => acknowledged_at = Some(decode_u64(payload)?),
+            _ => {}
+        }
+        Ok(())
+    })?;
+
+    Ok(Alert { alert_id, alert_type, severity, title, description, vehicle_id, location, timestamp, tags, acknowledged, acknowledged_by, acknowledged_at })
+}
+
+fn encode_trust_metrics_fields(t: &TrustMetrics) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_field(&mut buf, 1, t.overall_score.to_le_bytes().to_vec());
+    write_field(&mut buf, 2, t.behavior_score.to_le_bytes().to_vec());
+    write_field(&mut buf, 3, t.certificate_score.to_le_bytes().to_vec());
+    write_field(&mut buf, 4, t.history_score.to_le_bytes().to_vec());
+    write_field(&mut buf, 5, t.proximity_score.to_le_bytes().to_vec());
+    write_field(&mut buf, 6, t.sensor_score.to_le_bytes().to_vec());
+    if !t.factors.is_empty() {
+        write_field(&mut buf, 7, {
+            let mut p = Vec::new();
+            crate::codec::write_vec(&mut p, &t.factors, crate::codec::write_trust_factor);
+            p
+        });
+    }
+    if !t.flags.is_empty() {
+        write_field(&mut buf, 8, {
+            let mut p = Vec::new();
+            crate::codec::write_vec(&mut p, &t.flags, |b, s| crate::codec::write_string(b, s));
+            p
+        });
+    }
+    write_field(&mut buf, 9, t.last_update.to_le_bytes().to_vec());
+    write_field(&mut buf, 10, t.next_update.to_le_bytes().to_vec());
+    write_field(&mut buf, 11, t.anomaly_score.to_le_bytes().to_vec());
+    if !t.anomalies.is_empty() {
+        write_field(&mut buf, 12, {
+            let mut p = Vec::new();
+            crate::codec::write_vec(&mut p, &t.anomalies, |b, v| b.push(v.to_wire_u8()));
+            p
+        });
+    }
+    write_field(&mut buf, 13, t.anomaly_count.to_le_bytes().to_vec());
+    buf
+}
+
+fn decode_trust_metrics_fields(buf: &[u8]) -> Result<TrustMetrics, LogError> {
+    let mut overall_score = 0.0f32;
+    let mut behavior_score = 0.0f32;
+    let mut certificate_score = 0.0f32;
+    let mut history_score = 0.0f32;
+    let mut proximity_score = 0.0f32;
+    let mut sensor_score = 0.0f32;
+    let mut factors: Vec<TrustFactor> = Vec::new();
+    let mut flags = Vec::new();
+    let mut last_update = 0u64;
+    let mut next_update = 0u64;
+    let mut anomaly_score = 0.0f32;
+    let mut anomalies = Vec::new();
+    let mut anomaly_count = 0u32;
+
+    for_each_field(buf, |index, payload| {
+        match index {
+            1 => overall_score = decode_f32(payload)?,
+            2 => behavior_score = decode_f32(payload)?,
+            3 => certificate_score = decode_f32(payload)?,
+            4 => history_score = decode_f32(payload)?,
+            5 => proximity_score = decode_f32(payload)?,
+            6 => sensor_score = decode_f32(payload)?,
+            7 => factors = crate::codec::Reader::new(payload).vec(crate::codec::read_trust_factor)?,
+            8 => flags = crate::codec::Reader::new(payload).vec(|r| r.string())?,
+            9 => last_update = decode_u64(payload)?,
+            10 => next_update = decode_u64(payload)?,
+            11 => anomaly_score = decode_f32(payload)?,
+            12 => anomalies = crate::codec::Reader::new(payload).vec(|r| AnomalyType::from_wire_u8(r.u8()?))?,
+            13 => anomaly_count = decode_u32(payload)?,
+            _ => {}
+        }
+        Ok(())
+    })?;
+
+    Ok(TrustMetrics { overall_score, behavior_score, certificate_score, history_score, proximity_score, sensor_score, factors, flags, last_update, next_update, anomaly_score, anomalies, anomaly_count })
+}
+
+fn encode_envelope(sequence: u64, timestamp: DateTime<Utc>, record: &LogRecord) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&sequence.to_le_bytes());
+    buf.extend_from_slice(&timestamp.timestamp_nanos_opt().unwrap_or(0).to_le_bytes());
+    let (tag, fields) = match record {
+        LogRecord::VehiclePosition(v) => (TAG_VEHICLE_POSITION, encode_vehicle_position_fields(v)),
+        LogRecord::SensorReading(s) => (TAG_SENSOR_READING, encode_sensor_reading_fields(s)),
+        LogRecord::Alert(a) => (TAG_ALERT, encode_alert_fields(a)),
+        LogRecord::TrustMetrics(t) => (TAG_TRUST_METRICS, encode_trust_metrics_fields(t)),
+    };
+    buf.push(tag);
+    buf.extend_from_slice(&fields);
+    buf
+}
+
+fn decode_envelope(buf: &[u8]) -> Result<(u64, DateTime<Utc>, LogRecord), LogError> {
+    if buf.len() < 17 {
+        return Err(LogError::Truncated);
+    }
+    let sequence = u64::from_le_bytes(buf[0..8].try_into().unwrap());
+    let nanos = i64::from_le_bytes(buf[8..16].try_into().unwrap());
+    let timestamp = DateTime::from_timestamp(nanos.div_euclid(1_000_000_000), nanos.rem_euclid(1_000_000_000) as u32)
+        .ok_or(LogError::InvalidTimestamp)?;
+    let tag = buf[16];
+    let fields = &buf[17..];
+    let record = match tag {
+        TAG_VEHICLE_POSITION => LogRecord::VehiclePosition(decode_vehicle_position_fields(fields)?),
+        TAG_SENSOR_READING => LogRecord::SensorReading(decode_sensor_reading_fields(fields)?),
+        TAG_ALERT => LogRecord::Alert(decode_alert_fields(fields)?),
+        TAG_TRUST_METRICS => LogRecord::TrustMetrics(decode_trust_metrics_fields(fields)?),
+        other => return Err(LogError::InvalidUnionTag(other)),
+    };
+    Ok((sequence, timestamp, record))
+}
+
+/// Appends framed, numbered-field envelopes to an append-only sink.
+/// Assigns each record the next monotonic sequence number, starting at 0.
+pub struct LogWriter<W: Write> {
+    writer: W,
+    next_sequence: u64,
+}
+
+impl<W: Write> LogWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, next_sequence: 0 }
+    }
+
+    /// Append `record`, stamped with `timestamp`, returning its assigned
+    /// sequence number.
+    pub fn append(&mut self, timestamp: DateTime<Utc>, record: &LogRecord) -> Result<u64, LogError> {
+        let sequence = self.next_sequence;
+        let envelope = encode_envelope(sequence, timestamp, record);
+        self.writer.write_all(&(envelope.len() as u32).to_le_bytes())?;
+        self.writer.write_all(&envelope)?;
+        self.writer.flush()?;
+        self.next_sequence += 1;
+        Ok(sequence)
+    }
+}
+
+/// Iterates length-prefixed envelopes out of a log, tolerating a truncated
+/// trailing record (e.g. from a process killed mid-write) by treating it
+/// as the end of the stream rather than an error.
+pub struct LogReader<R: Read> {
+    reader: R,
+    done: bool,
+}
+
+impl<R: Read> LogReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self { reader, done: false }
+    }
+}
+
+impl<R: Read> Iterator for LogReader<R> {
+    type Item = Result<(u64, DateTime<Utc>, LogRecord), LogError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut len_buf = [0u8; 4];
+        match self.reader.read_exact(&mut len_buf) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e.into()));
+            }
+        }
+
+        let len = u32::from_le_bytes(len_buf);
+        if len > MAX_ENVELOPE_LEN {
+            self.done = true;
+            return Some(Err(LogError::EnvelopeTooLarge { found: len, max: MAX_ENVELOPE_LEN }));
+        }
+
+        let mut envelope = vec![0u8; len as usize];
+        match self.reader.read_exact(&mut envelope) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e.into()));
+            }
+        }
+
+        Some(decode_envelope(&envelope))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_position() -> VehiclePosition {
+        VehiclePosition {
+            vehicle_id: VehicleId::new_v4(),
+            certificate_id: Some("cert-1".to_string()),
+            rsu_id: None,
+            position: Position { lat: 1.0, lon: 2.0, alt: None, accuracy_horizontal: None, accuracy_vertical: None, hdop: None, vdop: None, tdop: None, satellites_used: None, satellites_visible: None },
+            velocity: None,
+            heading: None,
+            speed_accuracy: None,
+            timestamp: Utc::now(),
+            sequence: 0,
+            epoch: 1,
+            metadata: None,
+            sensors: Vec::new(),
+            capabilities: Vec::new(),
+            trust: None,
+            security: None,
+            network: None,
+            route_waypoints: vec!["a".to_string()],
+            emergency_vehicle: false,
+            emergency_type: EmergencyType::NotEmergency,
+            priority_level: 0,
+            dynamics: None,
+        }
+    }
+
+    #[test]
+    fn test_write_and_read_round_trips_sequence_numbers() {
+        let mut storage = Vec::new();
+        {
+            let mut writer = LogWriter::new(&mut storage);
+            writer.append(Utc::now(), &LogRecord::VehiclePosition(sample_position())).unwrap();
+            writer.append(Utc::now(), &LogRecord::VehiclePosition(sample_position())).unwrap();
+        }
+
+        let reader = LogReader::new(storage.as_slice());
+        let records: Vec<_> = reader.map(|r| r.unwrap()).collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].0, 0);
+        assert_eq!(records[1].0, 1);
+    }
+
+    #[test]
+    fn test_unknown_field_index_is_skipped() {
+        let mut buf = Vec::new();
+        write_field(&mut buf, 1, VehicleId::new_v4().as_bytes().to_vec());
+        write_field(&mut buf, 9999, vec![1, 2, 3, 4, 5]);
+        write_field(&mut buf, 4, {
+            let mut p = Vec::new();
+            crate::codec::write_position(&mut p, &default_position());
+            p
+        });
+        write_field(&mut buf, 8, Utc::now().timestamp_nanos_opt().unwrap_or(0).to_le_bytes().to_vec());
+        write_field(&mut buf, 9, 0u64.to_le_bytes().to_vec());
+        write_field(&mut buf, 10, 1u64.to_le_bytes().to_vec());
+        write_field(&mut buf, 20, 0u32.to_le_bytes().to_vec());
+
+        let decoded = decode_vehicle_position_fields(&buf).unwrap();
+        assert_eq!(decoded.epoch, 1);
+    }
+
+    #[test]
+    fn test_missing_fields_decode_to_defaults() {
+        let buf = Vec::new();
+        let decoded = decode_vehicle_position_fields(&buf).unwrap();
+        assert_eq!(decoded.vehicle_id, VehicleId::nil());
+        assert_eq!(decoded.sequence, 0);
+        assert!(decoded.sensors.is_empty());
+    }
+
+    #[test]
+    fn test_truncated_trailing_record_is_tolerated() {
+        let mut storage = Vec::new();
+        {
+            let mut writer = LogWriter::new(&mut storage);
+            writer.append(Utc::now(), &LogRecord::VehiclePosition(sample_position())).unwrap();
+        }
+        storage.extend_from_slice(&100u32.to_le_bytes());
+        storage.extend_from_slice(&[1, 2, 3]);
+
+        let reader = LogReader::new(storage.as_slice());
+        let records: Result<Vec<_>, _> = reader.collect();
+        let records = records.unwrap();
+        assert_eq!(records.len(), 1);
+    }
+
+    #[test]
+    fn test_implausible_length_prefix_is_rejected_without_allocating() {
+        let mut storage = Vec::new();
+        storage.extend_from_slice(&u32::MAX.to_le_bytes());
+        storage.extend_from_slice(&[1, 2, 3]);
+
+        let mut reader = LogReader::new(storage.as_slice());
+        let result = reader.next().unwrap();
+        assert!(matches!(result, Err(LogError::EnvelopeTooLarge { found: u32::MAX, .. })));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_sensor_reading_round_trips() {
+        let original = SensorReading {
+            sensor_type: SensorType::WheelSpeed,
+            value: 42.0,
+            accuracy: Some(0.5),
+            timestamp: Utc::now(),
+            unit: "rpm".to_string(),
+            min_value: None,
+            max_value: None,
+            is_calibrated: true,
+            calibration_date: None,
+        };
+        let encoded = encode_sensor_reading_fields(&original);
+        let decoded = decode_sensor_reading_fields(&encoded).unwrap();
+        assert_eq!(decoded.sensor_type, original.sensor_type);
+        assert_eq!(decoded.value, original.value);
+        assert_eq!(decoded.unit, original.unit);
+    }
+}
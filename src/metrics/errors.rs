@@ -0,0 +1,89 @@
+//! Counts errors by their taxonomy so operators can alert on spikes in a
+//! specific `error_code()`, e.g. `SECURITY_ERROR` or `EXTERNAL_SERVICE_ERROR`.
+
+use once_cell::sync::Lazy;
+use prometheus::{IntCounterVec, Opts};
+
+use crate::error::{GeoVANError, Result};
+use crate::metrics::REGISTRY;
+
+static ERRORS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    let counter = IntCounterVec::new(
+        Opts::new("geovan_errors_total", "Total errors observed, labeled by taxonomy"),
+        &["code", "http_status", "recoverable"],
+    )
+    .expect("metric name and labels are valid");
+    REGISTRY.register(Box::new(counter.clone())).expect("geovan_errors_total registered exactly once");
+    counter
+});
+
+static SECURITY_ERRORS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    let counter = IntCounterVec::new(
+        Opts::new("geovan_security_errors_total", "Total security-relevant errors, labeled by code"),
+        &["code"],
+    )
+    .expect("metric name and labels are valid");
+    REGISTRY
+        .register(Box::new(counter.clone()))
+        .expect("geovan_security_errors_total registered exactly once");
+    counter
+});
+
+/// Increment `geovan_errors_total` (and `geovan_security_errors_total` when
+/// applicable) for `error`.
+pub fn record(error: &GeoVANError) {
+    ERRORS_TOTAL
+        .with_label_values(&[
+            error.error_code(),
+            &error.http_status_code().to_string(),
+            &error.is_recoverable().to_string(),
+        ])
+        .inc();
+
+    if error.is_security_error() {
+        SECURITY_ERRORS_TOTAL.with_label_values(&[error.error_code()]).inc();
+    }
+}
+
+/// Lets handlers record a `Result`'s error (if any) in one call:
+/// `do_thing().record_err()?`.
+pub trait RecordErrExt<T> {
+    fn record_err(self) -> Result<T>;
+}
+
+impl<T> RecordErrExt<T> for Result<T> {
+    fn record_err(self) -> Result<T> {
+        if let Err(ref error) = self {
+            record(error);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{AuthenticationError, ValidationError};
+
+    #[test]
+    fn test_record_increments_errors_total() {
+        let before = ERRORS_TOTAL.with_label_values(&["VALIDATION_ERROR", "400", "false"]).get();
+        record(&GeoVANError::Validation(ValidationError::RequiredFieldMissing("email".to_string())));
+        let after = ERRORS_TOTAL.with_label_values(&["VALIDATION_ERROR", "400", "false"]).get();
+        assert_eq!(after, before + 1);
+    }
+
+    #[test]
+    fn test_record_increments_security_counter_for_security_errors() {
+        let before = SECURITY_ERRORS_TOTAL.with_label_values(&["AUTHENTICATION_ERROR"]).get();
+        record(&GeoVANError::Authentication(AuthenticationError::InvalidCredentials));
+        let after = SECURITY_ERRORS_TOTAL.with_label_values(&["AUTHENTICATION_ERROR"]).get();
+        assert_eq!(after, before + 1);
+    }
+
+    #[test]
+    fn test_record_err_passes_through_ok() {
+        let result: Result<u32> = Ok(42).record_err();
+        assert_eq!(result.unwrap(), 42);
+    }
+}
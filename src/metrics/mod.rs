@@ -0,0 +1,27 @@
+//! Prometheus-style metrics surface for the GeoVAN system.
+//!
+//! All counters/gauges register into [`REGISTRY`] so a single scrape
+//! endpoint (see [`gather`]) exposes everything the process collects.
+
+pub mod errors;
+
+use once_cell::sync::Lazy;
+use prometheus::Registry;
+
+/// The process-wide metrics registry. Every metric defined under this
+/// module registers into it exactly once, at first use.
+pub static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
+
+/// Render the current state of [`REGISTRY`] in Prometheus text exposition
+/// format, for a `/metrics` HTTP handler to return as-is.
+pub fn gather() -> String {
+    use prometheus::Encoder;
+
+    let encoder = prometheus::TextEncoder::new();
+    let metric_families = REGISTRY.gather();
+    let mut buffer = Vec::new();
+    encoder
+        .encode(&metric_families, &mut buffer)
+        .expect("encoding already-validated metric families cannot fail");
+    String::from_utf8(buffer).expect("Prometheus text encoding is always valid UTF-8")
+}
@@ -0,0 +1,209 @@
+//! Managed connection pools for Postgres, Redis, and RabbitMQ, built from
+//! [`Config`](crate::config::Config) with health-checked recycling and
+//! full-jitter exponential-backoff retry on initial connection.
+
+use std::future::Future;
+use std::time::Duration;
+
+use deadpool::managed::{Pool, PoolError as DeadpoolError};
+use deadpool_lapin::Manager as LapinManager;
+use deadpool_postgres::{Manager as PgManager, ManagerConfig, RecyclingMethod};
+use deadpool_redis::Manager as RedisManager;
+use rand::Rng;
+use thiserror::Error;
+use tokio_postgres::NoTls;
+
+use crate::config::{Config, RetryConfig};
+
+pub type PgPool = Pool<PgManager>;
+pub type RedisPool = Pool<RedisManager>;
+pub type RabbitMQPool = Pool<LapinManager>;
+
+/// All three managed pools, built together so a single `?` surfaces which
+/// backend failed.
+pub struct Pools {
+    pub postgres: PgPool,
+    pub redis: RedisPool,
+    pub rabbitmq: RabbitMQPool,
+}
+
+#[derive(Debug, Error)]
+pub enum PoolError {
+    #[error("failed to build postgres pool after retries: {0}")]
+    Postgres(String),
+    #[error("failed to build redis pool after retries: {0}")]
+    Redis(String),
+    #[error("failed to build rabbitmq pool after retries: {0}")]
+    RabbitMQ(String),
+}
+
+/// Retry `attempt` with full-jitter exponential backoff: sleep a random
+/// duration in `[0, min(max, base * 2^attempt)]` between tries, giving up
+/// after `policy.max_retries` and returning the last error.
+async fn retry_with_backoff<T, E, F, Fut>(policy: &RetryConfig, mut op: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut last_err = None;
+    let max_retries = policy.max_retries.max(1);
+    for attempt in 0..max_retries {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                last_err = Some(err);
+                let cap = policy.base.mul_f64(2f64.powi(attempt as i32)).min(policy.max);
+                let jittered = Duration::from_secs_f64(rand::thread_rng().gen_range(0.0..=cap.as_secs_f64().max(0.0)));
+                if attempt + 1 < max_retries {
+                    tokio::time::sleep(jittered).await;
+                }
+            }
+        }
+    }
+    Err(last_err.expect("at least one attempt is always made"))
+}
+
+/// Build a `deadpool-postgres` pool, retrying the initial connectivity
+/// check with full-jitter exponential backoff.
+pub async fn build_postgres_pool(config: &Config) -> Result<PgPool, PoolError> {
+    let db = &config.database;
+    let pg_config = db.url.parse::<tokio_postgres::Config>().map_err(|e| PoolError::Postgres(e.to_string()))?;
+
+    let manager = PgManager::from_config(pg_config, NoTls, ManagerConfig { recycling_method: RecyclingMethod::Fast });
+    let pool = Pool::builder(manager)
+        .max_size(db.max_connections as usize)
+        .create_timeout(Some(db.connection_timeout))
+        .build()
+        .map_err(|e| PoolError::Postgres(e.to_string()))?;
+
+    retry_with_backoff(&db.retry, || async {
+        pool.get().await.map(|_| ()).map_err(|e: DeadpoolError<tokio_postgres::Error>| e.to_string())
+    })
+    .await
+    .map_err(PoolError::Postgres)?;
+
+    Ok(pool)
+}
+
+/// Build a `deadpool-redis` pool, retrying the initial connectivity check
+/// with full-jitter exponential backoff.
+pub async fn build_redis_pool(config: &Config) -> Result<RedisPool, PoolError> {
+    let redis = &config.redis;
+    let manager = RedisManager::new(redis.url.clone()).map_err(|e| PoolError::Redis(e.to_string()))?;
+    let pool = Pool::builder(manager)
+        .max_size(redis.pool_size as usize)
+        .create_timeout(Some(redis.connection_timeout))
+        .build()
+        .map_err(|e| PoolError::Redis(e.to_string()))?;
+
+    retry_with_backoff(&redis.retry, || async {
+        pool.get().await.map(|_| ()).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(PoolError::Redis)?;
+
+    Ok(pool)
+}
+
+/// Build a `deadpool-lapin` pool, retrying the initial connectivity check
+/// with full-jitter exponential backoff.
+pub async fn build_rabbitmq_pool(config: &Config) -> Result<RabbitMQPool, PoolError> {
+    let rabbitmq = &config.rabbitmq;
+    let manager = LapinManager::new(rabbitmq.url.clone(), lapin::ConnectionProperties::default());
+    let pool = Pool::builder(manager)
+        .max_size(10)
+        .create_timeout(Some(rabbitmq.connection_timeout))
+        .build()
+        .map_err(|e| PoolError::RabbitMQ(e.to_string()))?;
+
+    retry_with_backoff(&rabbitmq.retry, || async {
+        pool.get().await.map(|_| ()).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(PoolError::RabbitMQ)?;
+
+    Ok(pool)
+}
+
+impl Config {
+    /// Build the Postgres, Redis, and RabbitMQ pools described by this
+    /// configuration, each with health-checked recycling and an
+    /// acquire timeout honoring the corresponding `connection_timeout`.
+    pub async fn build_pools(&self) -> Result<Pools, PoolError> {
+        Ok(Pools {
+            postgres: build_postgres_pool(self).await?,
+            redis: build_redis_pool(self).await?,
+            rabbitmq: build_rabbitmq_pool(self).await?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RetryConfig;
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_gives_up_after_max_retries() {
+        let policy = RetryConfig { base: Duration::from_millis(1), max: Duration::from_millis(5), max_retries: 3 };
+        let mut attempts = 0;
+        let result: Result<(), &str> = retry_with_backoff(&policy, || {
+            attempts += 1;
+            async { Err("still failing") }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_succeeds_eventually() {
+        let policy = RetryConfig { base: Duration::from_millis(1), max: Duration::from_millis(5), max_retries: 5 };
+        let mut attempts = 0;
+        let result = retry_with_backoff(&policy, || {
+            attempts += 1;
+            let succeed = attempts >= 2;
+            async move { if succeed { Ok(()) } else { Err("not yet") } }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_makes_one_attempt_when_max_retries_is_zero() {
+        let policy = RetryConfig { base: Duration::from_millis(1), max: Duration::from_millis(5), max_retries: 0 };
+        let mut attempts = 0;
+        let result: Result<(), &str> = retry_with_backoff(&policy, || {
+            attempts += 1;
+            async { Err("still failing") }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retry_with_backoff_does_not_sleep_after_final_failure() {
+        let policy = RetryConfig { base: Duration::from_secs(1), max: Duration::from_secs(10), max_retries: 3 };
+        let mut attempts = 0;
+        let mut last_attempt_at = None;
+        let result: Result<(), &str> = retry_with_backoff(&policy, || {
+            attempts += 1;
+            last_attempt_at = Some(tokio::time::Instant::now());
+            async { Err("still failing") }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 3);
+        // With the clock paused, the only way time advances is via
+        // `sleep` (the backoffs between attempts do advance it), so a
+        // prompt return after the last failed attempt means no time
+        // should have advanced since that attempt started.
+        assert_eq!(last_attempt_at.unwrap().elapsed(), Duration::ZERO);
+    }
+}
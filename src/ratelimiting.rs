@@ -0,0 +1,256 @@
+//! Token-bucket rate limiting wired to [`SecurityConfig`](crate::config::SecurityConfig).
+//!
+//! Each key (client IP, pseudonym, or login identity) maps to a bucket
+//! holding up to `capacity` tokens, refilled continuously at `refill_rate`
+//! tokens/second. `check(key)` computes `tokens = min(capacity, tokens +
+//! elapsed_secs * refill_rate)`; if at least one token is available it's
+//! consumed and the call is allowed, otherwise the caller is told how long
+//! to wait for the next token.
+//!
+//! [`RateLimiter::for_login`] models `max_login_attempts`/`lockout_duration`
+//! lockout on the same machinery as the general-purpose limiter. [`authenticate`]
+//! is the integration point: it checks the login limiter for the given
+//! identity before running password verification at all, so a locked-out
+//! identity never reaches credential verification.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::config::SecurityConfig;
+
+/// Outcome of a rate-limit check.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RateLimitDecision {
+    Allowed,
+    Denied { retry_after: Duration },
+}
+
+impl RateLimitDecision {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, RateLimitDecision::Allowed)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: f64) -> Self {
+        Self { tokens: capacity, last_refill: Instant::now() }
+    }
+
+    /// Refill based on elapsed time, then try to consume one token.
+    fn check(&mut self, capacity: f64, refill_rate: f64) -> RateLimitDecision {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * refill_rate).min(capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            RateLimitDecision::Allowed
+        } else {
+            let missing = 1.0 - self.tokens;
+            let wait_secs = if refill_rate > 0.0 { missing / refill_rate } else { f64::MAX };
+            RateLimitDecision::Denied { retry_after: Duration::from_secs_f64(wait_secs) }
+        }
+    }
+}
+
+/// Current capacity/refill settings, updated in place so buckets stay
+/// hot-reloadable from a live [`crate::config::Config`] without losing state.
+#[derive(Debug, Clone, Copy)]
+struct Limits {
+    capacity: f64,
+    refill_rate: f64,
+}
+
+/// A per-key token-bucket rate limiter.
+pub struct RateLimiter {
+    buckets: RwLock<HashMap<String, (TokenBucket, Instant)>>,
+    limits: RwLock<Limits>,
+    idle_ttl: Duration,
+}
+
+impl RateLimiter {
+    /// Build a limiter from `rate_limit_per_minute`, refilling at
+    /// `rate_limit_per_minute / 60` tokens per second.
+    pub fn new(rate_limit_per_minute: u32, idle_ttl: Duration) -> Self {
+        let capacity = rate_limit_per_minute as f64;
+        Self {
+            buckets: RwLock::new(HashMap::new()),
+            limits: RwLock::new(Limits { capacity, refill_rate: capacity / 60.0 }),
+            idle_ttl,
+        }
+    }
+
+    /// Build a limiter from [`SecurityConfig`], honoring `rate_limiting`
+    /// being disabled by returning a limiter with effectively unbounded
+    /// capacity (every call allowed).
+    pub fn from_security_config(security: &SecurityConfig, idle_ttl: Duration) -> Self {
+        if security.rate_limiting {
+            Self::new(security.rate_limit_per_minute, idle_ttl)
+        } else {
+            Self::new(u32::MAX, idle_ttl)
+        }
+    }
+
+    /// Build a limiter modeling login-attempt throttling: up to
+    /// `max_login_attempts` attempts are allowed, replenishing fully over
+    /// `lockout_duration`. See [`authenticate`] for the intended call site.
+    pub fn for_login(max_login_attempts: u32, lockout_duration: Duration) -> Self {
+        let capacity = max_login_attempts.max(1) as f64;
+        let refill_rate = capacity / lockout_duration.as_secs_f64().max(1.0);
+        Self {
+            buckets: RwLock::new(HashMap::new()),
+            limits: RwLock::new(Limits { capacity, refill_rate }),
+            idle_ttl: lockout_duration,
+        }
+    }
+
+    /// Hot-reload capacity/refill rate from an updated `SecurityConfig`,
+    /// without discarding the state of existing per-key buckets.
+    pub fn reload(&self, security: &SecurityConfig) {
+        let mut limits = self.limits.write().unwrap();
+        if security.rate_limiting {
+            limits.capacity = security.rate_limit_per_minute as f64;
+            limits.refill_rate = limits.capacity / 60.0;
+        } else {
+            limits.capacity = u32::MAX as f64;
+            limits.refill_rate = u32::MAX as f64 / 60.0;
+        }
+    }
+
+    /// Check whether `key` has a token available, consuming it if so.
+    pub fn check(&self, key: &str) -> RateLimitDecision {
+        let limits = *self.limits.read().unwrap();
+        let mut buckets = self.buckets.write().unwrap();
+        let entry = buckets
+            .entry(key.to_string())
+            .or_insert_with(|| (TokenBucket::new(limits.capacity), Instant::now()));
+        entry.1 = Instant::now();
+        entry.0.check(limits.capacity, limits.refill_rate)
+    }
+
+    /// Drop buckets that haven't been touched in over `idle_ttl`, so memory
+    /// stays bounded under high client churn.
+    pub fn evict_idle(&self) {
+        let now = Instant::now();
+        let idle_ttl = self.idle_ttl;
+        self.buckets
+            .write()
+            .unwrap()
+            .retain(|_, (_, last_seen)| now.duration_since(*last_seen) < idle_ttl);
+    }
+
+    pub fn bucket_count(&self) -> usize {
+        self.buckets.read().unwrap().len()
+    }
+}
+
+/// Log in `identity`, enforcing `limiter`'s lockout before `verify_password`
+/// ever runs. `limiter` should be built with [`RateLimiter::for_login`] so
+/// `max_login_attempts`/`lockout_duration` are the ones in effect, keyed by
+/// login identity (e.g. username or email) rather than client IP.
+pub fn authenticate(
+    limiter: &RateLimiter,
+    identity: &str,
+    verify_password: impl FnOnce() -> bool,
+) -> Result<(), crate::error::AuthenticationError> {
+    if !limiter.check(identity).is_allowed() {
+        return Err(crate::error::AuthenticationError::TooManyLoginAttempts);
+    }
+    if verify_password() {
+        Ok(())
+    } else {
+        Err(crate::error::AuthenticationError::InvalidCredentials)
+    }
+}
+
+/// Spawn a background task that periodically evicts idle buckets from
+/// `limiter` so memory stays bounded under high client churn.
+pub fn spawn_evictor(limiter: std::sync::Arc<RateLimiter>, sweep_interval: Duration) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(sweep_interval);
+        loop {
+            interval.tick().await;
+            limiter.evict_idle();
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows_within_capacity() {
+        let limiter = RateLimiter::new(60, Duration::from_secs(60));
+        assert!(limiter.check("client-1").is_allowed());
+    }
+
+    #[test]
+    fn test_denies_once_exhausted() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60));
+        assert!(limiter.check("client-1").is_allowed());
+        let decision = limiter.check("client-1");
+        assert!(!decision.is_allowed());
+        assert!(matches!(decision, RateLimitDecision::Denied { .. }));
+    }
+
+    #[test]
+    fn test_login_limiter_models_lockout() {
+        let limiter = RateLimiter::for_login(5, Duration::from_secs(900));
+        for _ in 0..5 {
+            assert!(limiter.check("user@example.com").is_allowed());
+        }
+        assert!(!limiter.check("user@example.com").is_allowed());
+    }
+
+    #[test]
+    fn test_authenticate_rejects_bad_password_without_consuming_extra_attempts() {
+        let limiter = RateLimiter::for_login(5, Duration::from_secs(900));
+        let result = authenticate(&limiter, "user@example.com", || false);
+        assert!(matches!(result, Err(crate::error::AuthenticationError::InvalidCredentials)));
+        // The failed login consumed exactly one of the five attempts.
+        for _ in 0..4 {
+            assert!(limiter.check("user@example.com").is_allowed());
+        }
+        assert!(!limiter.check("user@example.com").is_allowed());
+    }
+
+    #[test]
+    fn test_authenticate_allows_good_password() {
+        let limiter = RateLimiter::for_login(5, Duration::from_secs(900));
+        let result = authenticate(&limiter, "user@example.com", || true);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_authenticate_locks_out_after_max_attempts_without_verifying_password() {
+        let limiter = RateLimiter::for_login(3, Duration::from_secs(900));
+        for _ in 0..3 {
+            let _ = authenticate(&limiter, "user@example.com", || false);
+        }
+        let mut verify_called = false;
+        let result = authenticate(&limiter, "user@example.com", || {
+            verify_called = true;
+            true
+        });
+        assert!(matches!(result, Err(crate::error::AuthenticationError::TooManyLoginAttempts)));
+        assert!(!verify_called);
+    }
+
+    #[test]
+    fn test_evict_idle_removes_stale_buckets() {
+        let limiter = RateLimiter::new(60, Duration::from_millis(1));
+        limiter.check("client-1");
+        std::thread::sleep(Duration::from_millis(5));
+        limiter.evict_idle();
+        assert_eq!(limiter.bucket_count(), 0);
+    }
+}
@@ -0,0 +1,274 @@
+//! Secret-source resolution for sensitive configuration fields.
+//!
+//! Sensitive fields like `jwt_secret` and `encryption_key` are never stored
+//! as plaintext config values in production. Instead they're declared as a
+//! [`SecretSource`] — a pointer to where the real value lives — and resolved
+//! lazily at load time through a chain modeled on the AWS credential-provider
+//! chain: environment variable, then a profile file, then an IMDS/web-identity
+//! token endpoint. There is no static fallback: if none of these yields a
+//! value, resolution fails with [`SecretError::ChainExhausted`].
+
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+/// A resolved secret value. Zeroized on drop so it doesn't linger in memory.
+pub type Secret = Zeroizing<String>;
+
+#[derive(Debug, Error)]
+pub enum SecretError {
+    #[error("environment variable {0} is not set")]
+    EnvVarMissing(String),
+    #[error("failed to read secret file {path}: {source}")]
+    FileRead {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("credential-provider chain exhausted: no source yielded a value")]
+    ChainExhausted,
+    #[error("refusing to parse reserved placeholder {REDACTED_PLACEHOLDER:?} as a literal inline secret")]
+    ReservedPlaceholder,
+}
+
+/// The profile file consulted by the credential-provider chain, mirroring
+/// `~/.aws/credentials`-style lookups.
+const PROFILE_FILE_ENV: &str = "GEOVAN_CREDENTIALS_FILE";
+const DEFAULT_PROFILE_FILE: &str = "~/.geovan/credentials";
+
+/// IMDS-style metadata endpoint used as a last resort before falling back to
+/// a static value. Overridable for testing.
+const IMDS_ENDPOINT_ENV: &str = "GEOVAN_IMDS_ENDPOINT";
+
+/// Placeholder [`SecretSource::Inline`] is serialized as, so the literal
+/// secret never round-trips through a config dump. Reserved: [`parse`] below
+/// refuses to accept it back as a literal inline value.
+const REDACTED_PLACEHOLDER: &str = "<redacted>";
+
+/// Where a sensitive configuration value should be read from.
+#[derive(Clone, PartialEq, Eq)]
+pub enum SecretSource {
+    /// The value is the literal secret (development/testing only).
+    Inline(String),
+    /// Read from the named environment variable.
+    Env(String),
+    /// Read from a file on disk, trimmed of trailing whitespace.
+    File(PathBuf),
+    /// Resolve through the full credential-provider chain: environment
+    /// variable → profile file → IMDS/web-identity token, failing with
+    /// [`SecretError::ChainExhausted`] when none yields a value.
+    CredentialChain,
+}
+
+/// Redacts `Inline`'s literal value the same way [`fmt::Display`] does, so
+/// a stray `tracing::debug!("{:?}", config)` or panic message can't leak a
+/// JWT secret or encryption key embedded in a [`SecretSource`] field.
+impl fmt::Debug for SecretSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretSource::Inline(_) => write!(f, "Inline({REDACTED_PLACEHOLDER})"),
+            SecretSource::Env(var) => f.debug_tuple("Env").field(var).finish(),
+            SecretSource::File(path) => f.debug_tuple("File").field(path).finish(),
+            SecretSource::CredentialChain => write!(f, "CredentialChain"),
+        }
+    }
+}
+
+impl SecretSource {
+    /// Parse the `SecretSource` directive syntax used in TOML/env config:
+    /// `env:VAR_NAME`, `file:/path/to/secret`, `imds`, `credential-chain`, or
+    /// `inline:literal-value`. A string with no recognized prefix is treated
+    /// as an inline literal for backwards compatibility with plain strings.
+    ///
+    /// Rejects [`REDACTED_PLACEHOLDER`] (with or without the `inline:`
+    /// prefix) so that feeding a serialized `SecretSource` back through
+    /// `parse` — e.g. a config dump that gets read, modified, and rewritten —
+    /// errors instead of silently resolving to the literal string
+    /// `"<redacted>"`.
+    pub fn parse(raw: &str) -> Result<Self, SecretError> {
+        if let Some(var) = raw.strip_prefix("env:") {
+            Ok(SecretSource::Env(var.to_string()))
+        } else if let Some(path) = raw.strip_prefix("file:") {
+            Ok(SecretSource::File(PathBuf::from(path)))
+        } else if raw == "imds" || raw == "credential-chain" {
+            Ok(SecretSource::CredentialChain)
+        } else if let Some(value) = raw.strip_prefix("inline:") {
+            if value == REDACTED_PLACEHOLDER {
+                Err(SecretError::ReservedPlaceholder)
+            } else {
+                Ok(SecretSource::Inline(value.to_string()))
+            }
+        } else if raw == REDACTED_PLACEHOLDER {
+            Err(SecretError::ReservedPlaceholder)
+        } else {
+            Ok(SecretSource::Inline(raw.to_string()))
+        }
+    }
+
+    /// Resolve this source into the actual secret value.
+    pub fn resolve(&self) -> Result<Secret, SecretError> {
+        match self {
+            SecretSource::Inline(value) => Ok(Zeroizing::new(value.clone())),
+            SecretSource::Env(var) => std::env::var(var)
+                .map(Zeroizing::new)
+                .map_err(|_| SecretError::EnvVarMissing(var.clone())),
+            SecretSource::File(path) => std::fs::read_to_string(path)
+                .map(|s| Zeroizing::new(s.trim().to_string()))
+                .map_err(|source| SecretError::FileRead {
+                    path: path.clone(),
+                    source,
+                }),
+            SecretSource::CredentialChain => resolve_credential_chain(),
+        }
+    }
+}
+
+/// Expand a leading `~` against `$HOME`. Paths without a leading `~`
+/// component are returned unchanged. Falls back to the original path if
+/// `$HOME` isn't set, so a misconfigured environment degrades to "file not
+/// found" rather than resolving to a bogus location.
+fn expand_home(path: &Path) -> PathBuf {
+    match path.strip_prefix("~") {
+        Ok(rest) => match std::env::var("HOME") {
+            Ok(home) => PathBuf::from(home).join(rest),
+            Err(_) => path.to_path_buf(),
+        },
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+/// AWS-style credential-provider chain: try the environment, then a profile
+/// file, then an IMDS/web-identity token endpoint, then give up.
+fn resolve_credential_chain() -> Result<Secret, SecretError> {
+    if let Ok(value) = std::env::var("GEOVAN_SECRET") {
+        return Ok(Zeroizing::new(value));
+    }
+
+    let profile_path = std::env::var(PROFILE_FILE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_PROFILE_FILE));
+    let profile_path = expand_home(&profile_path);
+    if let Ok(contents) = std::fs::read_to_string(&profile_path) {
+        let trimmed = contents.trim();
+        if !trimmed.is_empty() {
+            return Ok(Zeroizing::new(trimmed.to_string()));
+        }
+    }
+
+    if let Ok(endpoint) = std::env::var(IMDS_ENDPOINT_ENV) {
+        if let Ok(token) = fetch_imds_token(&endpoint) {
+            return Ok(token);
+        }
+    }
+
+    Err(SecretError::ChainExhausted)
+}
+
+/// Placeholder IMDS/web-identity token fetch. Real deployments would issue an
+/// HTTP request here; kept as a seam so the chain's shape is testable without
+/// network access.
+fn fetch_imds_token(_endpoint: &str) -> Result<Secret, SecretError> {
+    Err(SecretError::ChainExhausted)
+}
+
+impl fmt::Display for SecretSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretSource::Inline(_) => write!(f, "inline:{REDACTED_PLACEHOLDER}"),
+            SecretSource::Env(var) => write!(f, "env:{var}"),
+            SecretSource::File(path) => write!(f, "file:{}", path.display()),
+            SecretSource::CredentialChain => write!(f, "credential-chain"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        SecretSource::parse(&raw).map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for SecretSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // Never round-trip the literal value of an inline secret.
+        serializer.collect_str(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_variants() {
+        assert_eq!(SecretSource::parse("env:JWT_SECRET").unwrap(), SecretSource::Env("JWT_SECRET".to_string()));
+        assert_eq!(
+            SecretSource::parse("file:/etc/geovan/jwt").unwrap(),
+            SecretSource::File(PathBuf::from("/etc/geovan/jwt"))
+        );
+        assert_eq!(SecretSource::parse("imds").unwrap(), SecretSource::CredentialChain);
+        assert_eq!(SecretSource::parse("credential-chain").unwrap(), SecretSource::CredentialChain);
+        assert_eq!(
+            SecretSource::parse("inline:super-secret").unwrap(),
+            SecretSource::Inline("super-secret".to_string())
+        );
+        assert_eq!(SecretSource::parse("bare-value").unwrap(), SecretSource::Inline("bare-value".to_string()));
+    }
+
+    #[test]
+    fn test_parse_rejects_redacted_placeholder() {
+        assert!(matches!(SecretSource::parse("inline:<redacted>"), Err(SecretError::ReservedPlaceholder)));
+        assert!(matches!(SecretSource::parse("<redacted>"), Err(SecretError::ReservedPlaceholder)));
+    }
+
+    #[test]
+    fn test_serialized_inline_does_not_round_trip_through_parse() {
+        let source = SecretSource::Inline("actual-secret".to_string());
+        let serialized = source.to_string();
+        assert!(SecretSource::parse(&serialized).is_err());
+    }
+
+    #[test]
+    fn test_resolve_inline() {
+        let source = SecretSource::Inline("value".to_string());
+        assert_eq!(*source.resolve().unwrap(), "value");
+    }
+
+    #[test]
+    fn test_resolve_env() {
+        std::env::set_var("GEOVAN_TEST_SECRET_RESOLVE", "from-env");
+        let source = SecretSource::Env("GEOVAN_TEST_SECRET_RESOLVE".to_string());
+        assert_eq!(*source.resolve().unwrap(), "from-env");
+        std::env::remove_var("GEOVAN_TEST_SECRET_RESOLVE");
+    }
+
+    #[test]
+    fn test_resolve_env_missing() {
+        let source = SecretSource::Env("GEOVAN_TEST_SECRET_DOES_NOT_EXIST".to_string());
+        assert!(matches!(source.resolve(), Err(SecretError::EnvVarMissing(_))));
+    }
+
+    #[test]
+    fn test_debug_redacts_inline_secret() {
+        let source = SecretSource::Inline("actual-secret".to_string());
+        let debugged = format!("{source:?}");
+        assert!(!debugged.contains("actual-secret"));
+        assert_eq!(debugged, "Inline(<redacted>)");
+    }
+
+    #[test]
+    fn test_expand_home_rewrites_leading_tilde() {
+        std::env::set_var("HOME", "/home/operator");
+        assert_eq!(expand_home(Path::new("~/.geovan/credentials")), PathBuf::from("/home/operator/.geovan/credentials"));
+        assert_eq!(expand_home(Path::new("/etc/geovan/credentials")), PathBuf::from("/etc/geovan/credentials"));
+    }
+}
@@ -0,0 +1,201 @@
+//! Graceful shutdown coordination.
+//!
+//! A [`ShutdownCoordinator`] holds the single `watch` channel that every
+//! long-running service (tracking, trust, messaging consumers, DB pools)
+//! subscribes to, plus a registry of per-service drain completions so
+//! [`crate::shutdown`] can wait for everything to wind down — bounded by a
+//! deadline — before the pools are force-closed.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::sync::{oneshot, watch};
+
+use crate::error::{GeoVANError, ResourceError};
+use crate::pool::Pools;
+
+/// Handle returned by [`ShutdownCoordinator::register_drain`]. The holder
+/// calls [`DrainHandle::done`] once its cleanup (closing consumers,
+/// flushing in-flight writes, etc.) has finished.
+pub struct DrainHandle {
+    name: &'static str,
+    tx: Option<oneshot::Sender<()>>,
+}
+
+impl DrainHandle {
+    pub fn done(mut self) {
+        if let Some(tx) = self.tx.take() {
+            let _ = tx.send(());
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Coordinates a broadcast shutdown signal and waits for every registered
+/// service to drain before the caller force-closes shared resources.
+pub struct ShutdownCoordinator {
+    signal_tx: watch::Sender<bool>,
+    drains: Mutex<Vec<(&'static str, oneshot::Receiver<()>)>>,
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownCoordinator {
+    pub fn new() -> Self {
+        let (signal_tx, _) = watch::channel(false);
+        Self { signal_tx, drains: Mutex::new(Vec::new()) }
+    }
+
+    /// A receiver that flips to `true` once shutdown has been triggered.
+    /// Long-running services `select!` against this in their run loops.
+    pub fn subscribe(&self) -> watch::Receiver<bool> {
+        self.signal_tx.subscribe()
+    }
+
+    /// Register `name` as a service that must drain before `shutdown()`
+    /// returns. The caller signals completion via the returned handle.
+    pub fn register_drain(&self, name: &'static str) -> DrainHandle {
+        let (tx, rx) = oneshot::channel();
+        self.drains.lock().unwrap().push((name, rx));
+        DrainHandle { name, tx: Some(tx) }
+    }
+
+    /// Flip the shutdown signal without waiting for drains. Called by the
+    /// signal listener and by `trigger`-only callers (e.g. tests).
+    pub fn trigger(&self) {
+        let _ = self.signal_tx.send(true);
+    }
+
+    /// Spawn a task that triggers shutdown on SIGINT or SIGTERM.
+    #[cfg(unix)]
+    pub fn spawn_signal_listener(self: std::sync::Arc<Self>) -> tokio::task::JoinHandle<()> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        tokio::spawn(async move {
+            let mut sigterm = match signal(SignalKind::terminate()) {
+                Ok(s) => s,
+                Err(e) => {
+                    tracing::error!("failed to install SIGTERM handler: {e}");
+                    return;
+                }
+            };
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => tracing::info!("received SIGINT"),
+                _ = sigterm.recv() => tracing::info!("received SIGTERM"),
+            }
+            self.trigger();
+        })
+    }
+
+    /// Spawn a task that triggers shutdown on Ctrl-C (no SIGTERM outside unix).
+    #[cfg(not(unix))]
+    pub fn spawn_signal_listener(self: std::sync::Arc<Self>) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let _ = tokio::signal::ctrl_c().await;
+            tracing::info!("received Ctrl-C");
+            self.trigger();
+        })
+    }
+
+    /// Broadcast the shutdown signal, wait up to `deadline` for every
+    /// registered drain to complete, then force-close `pools` if given.
+    /// Services that miss the deadline are logged and skipped rather than
+    /// failing the whole shutdown, since the pools get force-closed either
+    /// way; the return value reports whether every drain finished in time.
+    pub async fn shutdown(&self, deadline: Duration, pools: Option<&Pools>) -> crate::error::Result<()> {
+        self.trigger();
+
+        let drains = std::mem::take(&mut *self.drains.lock().unwrap());
+        let start = std::time::Instant::now();
+        let mut timed_out = Vec::new();
+        for (name, rx) in drains {
+            let remaining = deadline.saturating_sub(start.elapsed());
+            match tokio::time::timeout(remaining, rx).await {
+                Ok(Ok(())) => tracing::debug!("{name} drained cleanly"),
+                Ok(Err(_)) => tracing::warn!("{name} dropped its drain handle without signaling completion"),
+                Err(_) => {
+                    tracing::warn!("{name} did not drain within the overall {deadline:?} shutdown budget");
+                    timed_out.push(name);
+                }
+            }
+        }
+
+        if let Some(pools) = pools {
+            pools.postgres.close();
+            pools.redis.close();
+            pools.rabbitmq.close();
+        }
+
+        if timed_out.is_empty() {
+            Ok(())
+        } else {
+            Err(GeoVANError::Resource(ResourceError::Timeout(format!(
+                "services failed to drain before shutdown deadline: {}",
+                timed_out.join(", ")
+            ))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_registered_drains() {
+        let coordinator = ShutdownCoordinator::new();
+        let handle = coordinator.register_drain("tracking");
+        let mut signal = coordinator.subscribe();
+
+        let drain_task = tokio::spawn(async move {
+            signal.changed().await.unwrap();
+            handle.done();
+        });
+
+        let result = coordinator.shutdown(Duration::from_secs(1), None).await;
+        assert!(result.is_ok());
+        drain_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_reports_timeout_for_stuck_drain() {
+        let coordinator = ShutdownCoordinator::new();
+        let _handle = coordinator.register_drain("stuck-service");
+
+        let result = coordinator.shutdown(Duration::from_millis(10), None).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_deadline_bounds_all_drains_not_each() {
+        let coordinator = ShutdownCoordinator::new();
+        let _first = coordinator.register_drain("stuck-one");
+        let _second = coordinator.register_drain("stuck-two");
+        let _third = coordinator.register_drain("stuck-three");
+
+        let deadline = Duration::from_millis(30);
+        let started = std::time::Instant::now();
+        let result = coordinator.shutdown(deadline, None).await;
+        let elapsed = started.elapsed();
+
+        assert!(result.is_err());
+        assert!(elapsed < deadline * 3, "shutdown took {elapsed:?}, expected well under {:?}", deadline * 3);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_observes_trigger() {
+        let coordinator = ShutdownCoordinator::new();
+        let mut signal = coordinator.subscribe();
+        assert!(!*signal.borrow());
+        coordinator.trigger();
+        signal.changed().await.unwrap();
+        assert!(*signal.borrow());
+    }
+}
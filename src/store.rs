@@ -0,0 +1,438 @@
+//! Pluggable persistence for per-vehicle position and trust history.
+//!
+//! [`TrustMetrics::history_score`](crate::core::TrustMetrics) and
+//! [`TrustMetrics::anomaly_count`](crate::core::TrustMetrics) are computed
+//! "last 24 hours" figures, which implies a queryable store of past
+//! [`VehiclePosition`] and [`TrustScoreUpdate`] records that didn't exist
+//! before this module. [`HistoryStore`] is the common interface; two
+//! interchangeable adapters sit behind it, selected at open time by
+//! [`StoreConfig`]: an embedded LMDB store for single-node deployments with
+//! no external dependency, and a SQLite store for anyone who'd rather
+//! shell out to `sqlite3` for ad-hoc analysis. Both key position records by
+//! `(VehicleId, epoch, sequence)`, matching [`VehiclePosition`]'s own
+//! fields, so a pseudonym rotation (a new `epoch`) doesn't collide with or
+//! shadow the vehicle's prior history.
+//!
+//! `TrustScoreUpdate` carries no `epoch`/`sequence` of its own, so trust
+//! records are instead keyed by `(VehicleId, timestamp)` — documented here
+//! rather than silently reusing the position scheme.
+//!
+//! Both backends are blocking under the hood (LMDB is a memory-mapped
+//! file, `rusqlite` is synchronous). Opening either store, and every LMDB
+//! call, is dispatched through [`tokio::task::spawn_blocking`]; the SQLite
+//! backend's reads, writes and compaction run inline on the calling task
+//! while holding its connection mutex.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+use crate::codec::{CodecError, WireCodec};
+use crate::core::{TrustScoreUpdate, VehicleId, VehiclePosition};
+
+#[derive(Debug, Error)]
+pub enum StoreError {
+    #[error("lmdb error: {0}")]
+    Lmdb(#[from] heed::Error),
+    #[error("sqlite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    #[error("failed to decode stored record: {0}")]
+    Codec(#[from] CodecError),
+    #[error("I/O error opening store: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Backend selection for [`open`]. Not part of [`crate::config::Config`]
+/// because a store is typically opened once per process with an explicit
+/// path, not hot-reloaded.
+#[derive(Debug, Clone)]
+pub enum StoreConfig {
+    Lmdb { path: PathBuf, map_size_bytes: usize },
+    Sqlite { path: PathBuf },
+}
+
+/// Queryable history of vehicle positions and trust-score changes.
+#[async_trait]
+pub trait HistoryStore: Send + Sync {
+    async fn put_position(&self, position: &VehiclePosition) -> Result<(), StoreError>;
+    async fn put_trust(&self, update: &TrustScoreUpdate) -> Result<(), StoreError>;
+    async fn recent_positions(&self, vehicle_id: VehicleId, since: DateTime<Utc>) -> Result<Vec<VehiclePosition>, StoreError>;
+    async fn trust_trend(&self, vehicle_id: VehicleId, window: Duration) -> Result<Vec<TrustScoreUpdate>, StoreError>;
+    /// Drop every record older than `horizon`, returning how many were removed.
+    async fn compact(&self, horizon: Duration) -> Result<u64, StoreError>;
+}
+
+/// Open the backend described by `config`.
+pub async fn open(config: StoreConfig) -> Result<Box<dyn HistoryStore>, StoreError> {
+    match config {
+        StoreConfig::Lmdb { path, map_size_bytes } => {
+            let store = tokio::task::spawn_blocking(move || LmdbHistoryStore::open_sync(&path, map_size_bytes))
+                .await
+                .expect("lmdb open task panicked")?;
+            Ok(Box::new(store))
+        }
+        StoreConfig::Sqlite { path } => {
+            let store =
+                tokio::task::spawn_blocking(move || SqliteHistoryStore::open_sync(&path)).await.expect("sqlite open task panicked")?;
+            Ok(Box::new(store))
+        }
+    }
+}
+
+fn position_key(vehicle_id: VehicleId, epoch: u64, sequence: u64) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    key[0..16].copy_from_slice(vehicle_id.as_bytes());
+    key[16..24].copy_from_slice(&epoch.to_be_bytes());
+    key[24..32].copy_from_slice(&sequence.to_be_bytes());
+    key
+}
+
+fn trust_key(vehicle_id: VehicleId, timestamp: DateTime<Utc>) -> [u8; 24] {
+    let mut key = [0u8; 24];
+    key[0..16].copy_from_slice(vehicle_id.as_bytes());
+    key[16..24].copy_from_slice(&timestamp.timestamp_nanos_opt().unwrap_or(0).to_be_bytes());
+    key
+}
+
+mod lmdb_store {
+    use super::*;
+    use heed::types::Bytes;
+    use heed::{Database, Env, EnvOpenOptions};
+
+    pub struct LmdbHistoryStore {
+        env: Env,
+        positions: Database<Bytes, Bytes>,
+        trust: Database<Bytes, Bytes>,
+    }
+
+    impl LmdbHistoryStore {
+        pub fn open_sync(path: &std::path::Path, map_size_bytes: usize) -> Result<Self, StoreError> {
+            std::fs::create_dir_all(path)?;
+            let env = unsafe { EnvOpenOptions::new().map_size(map_size_bytes).max_dbs(2).open(path)? };
+            let mut wtxn = env.write_txn()?;
+            let positions = env.create_database(&mut wtxn, Some("positions"))?;
+            let trust = env.create_database(&mut wtxn, Some("trust"))?;
+            wtxn.commit()?;
+            Ok(Self { env, positions, trust })
+        }
+    }
+
+    #[async_trait]
+    impl HistoryStore for LmdbHistoryStore {
+        async fn put_position(&self, position: &VehiclePosition) -> Result<(), StoreError> {
+            let env = self.env.clone();
+            let db = self.positions;
+            let key = position_key(position.vehicle_id, position.epoch, position.sequence);
+            let value = position.encode();
+            tokio::task::spawn_blocking(move || {
+                let mut wtxn = env.write_txn()?;
+                db.put(&mut wtxn, &key, &value)?;
+                wtxn.commit()?;
+                Ok::<(), StoreError>(())
+            })
+            .await
+            .expect("lmdb write task panicked")
+        }
+
+        async fn put_trust(&self, update: &TrustScoreUpdate) -> Result<(), StoreError> {
+            let env = self.env.clone();
+            let db = self.trust;
+            let key = trust_key(update.vehicle_id, update.timestamp);
+            let value = update.encode();
+            tokio::task::spawn_blocking(move || {
+                let mut wtxn = env.write_txn()?;
+                db.put(&mut wtxn, &key, &value)?;
+                wtxn.commit()?;
+                Ok::<(), StoreError>(())
+            })
+            .await
+            .expect("lmdb write task panicked")
+        }
+
+        async fn recent_positions(&self, vehicle_id: VehicleId, since: DateTime<Utc>) -> Result<Vec<VehiclePosition>, StoreError> {
+            let env = self.env.clone();
+            let db = self.positions;
+            tokio::task::spawn_blocking(move || {
+                let rtxn = env.read_txn()?;
+                let mut out = Vec::new();
+                for item in db.prefix_iter(&rtxn, vehicle_id.as_bytes())? {
+                    let (_, value) = item?;
+                    let position = VehiclePosition::decode(value)?;
+                    if position.timestamp >= since {
+                        out.push(position);
+                    }
+                }
+                Ok::<_, StoreError>(out)
+            })
+            .await
+            .expect("lmdb read task panicked")
+        }
+
+        async fn trust_trend(&self, vehicle_id: VehicleId, window: Duration) -> Result<Vec<TrustScoreUpdate>, StoreError> {
+            let env = self.env.clone();
+            let db = self.trust;
+            let cutoff = Utc::now() - chrono::Duration::from_std(window).unwrap_or(chrono::Duration::zero());
+            tokio::task::spawn_blocking(move || {
+                let rtxn = env.read_txn()?;
+                let mut out = Vec::new();
+                for item in db.prefix_iter(&rtxn, vehicle_id.as_bytes())? {
+                    let (_, value) = item?;
+                    let update = TrustScoreUpdate::decode(value)?;
+                    if update.timestamp >= cutoff {
+                        out.push(update);
+                    }
+                }
+                Ok::<_, StoreError>(out)
+            })
+            .await
+            .expect("lmdb read task panicked")
+        }
+
+        async fn compact(&self, horizon: Duration) -> Result<u64, StoreError> {
+            let env = self.env.clone();
+            let positions = self.positions;
+            let trust = self.trust;
+            let cutoff = Utc::now() - chrono::Duration::from_std(horizon).unwrap_or(chrono::Duration::zero());
+            tokio::task::spawn_blocking(move || {
+                let mut wtxn = env.write_txn()?;
+                let mut removed = 0u64;
+
+                let stale_position_keys: Vec<Vec<u8>> = positions
+                    .iter(&wtxn)?
+                    .filter_map(|item| item.ok())
+                    .filter(|(_, value)| VehiclePosition::decode(value).map(|p| p.timestamp < cutoff).unwrap_or(false))
+                    .map(|(key, _)| key.to_vec())
+                    .collect();
+                for key in stale_position_keys {
+                    if positions.delete(&mut wtxn, key.as_slice())? {
+                        removed += 1;
+                    }
+                }
+
+                let stale_trust_keys: Vec<Vec<u8>> = trust
+                    .iter(&wtxn)?
+                    .filter_map(|item| item.ok())
+                    .filter(|(_, value)| TrustScoreUpdate::decode(value).map(|t| t.timestamp < cutoff).unwrap_or(false))
+                    .map(|(key, _)| key.to_vec())
+                    .collect();
+                for key in stale_trust_keys {
+                    if trust.delete(&mut wtxn, key.as_slice())? {
+                        removed += 1;
+                    }
+                }
+
+                wtxn.commit()?;
+                Ok::<u64, StoreError>(removed)
+            })
+            .await
+            .expect("lmdb compact task panicked")
+        }
+    }
+}
+
+mod sqlite_store {
+    use super::*;
+    use rusqlite::{params, Connection};
+
+    pub struct SqliteHistoryStore {
+        conn: Mutex<Connection>,
+    }
+
+    impl SqliteHistoryStore {
+        pub fn open_sync(path: &std::path::Path) -> Result<Self, StoreError> {
+            let conn = Connection::open(path)?;
+            conn.execute_batch(
+                "CREATE TABLE IF NOT EXISTS positions (
+                    vehicle_id BLOB NOT NULL,
+                    epoch INTEGER NOT NULL,
+                    sequence INTEGER NOT NULL,
+                    timestamp_nanos INTEGER NOT NULL,
+                    payload BLOB NOT NULL,
+                    PRIMARY KEY (vehicle_id, epoch, sequence)
+                );
+                CREATE TABLE IF NOT EXISTS trust_updates (
+                    vehicle_id BLOB NOT NULL,
+                    timestamp_nanos INTEGER NOT NULL,
+                    payload BLOB NOT NULL,
+                    PRIMARY KEY (vehicle_id, timestamp_nanos)
+                );",
+            )?;
+            Ok(Self { conn: Mutex::new(conn) })
+        }
+    }
+
+    #[async_trait]
+    impl HistoryStore for SqliteHistoryStore {
+        async fn put_position(&self, position: &VehiclePosition) -> Result<(), StoreError> {
+            let conn = self.conn.lock().expect("sqlite connection mutex poisoned");
+            conn.execute(
+                "INSERT OR REPLACE INTO positions (vehicle_id, epoch, sequence, timestamp_nanos, payload) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    position.vehicle_id.as_bytes().to_vec(),
+                    position.epoch,
+                    position.sequence,
+                    position.timestamp.timestamp_nanos_opt().unwrap_or(0),
+                    position.encode(),
+                ],
+            )?;
+            Ok(())
+        }
+
+        async fn put_trust(&self, update: &TrustScoreUpdate) -> Result<(), StoreError> {
+            let conn = self.conn.lock().expect("sqlite connection mutex poisoned");
+            conn.execute(
+                "INSERT OR REPLACE INTO trust_updates (vehicle_id, timestamp_nanos, payload) VALUES (?1, ?2, ?3)",
+                params![
+                    update.vehicle_id.as_bytes().to_vec(),
+                    update.timestamp.timestamp_nanos_opt().unwrap_or(0),
+                    update.encode(),
+                ],
+            )?;
+            Ok(())
+        }
+
+        async fn recent_positions(&self, vehicle_id: VehicleId, since: DateTime<Utc>) -> Result<Vec<VehiclePosition>, StoreError> {
+            let conn = self.conn.lock().expect("sqlite connection mutex poisoned");
+            let mut stmt = conn.prepare(
+                "SELECT payload FROM positions WHERE vehicle_id = ?1 AND timestamp_nanos >= ?2 ORDER BY epoch, sequence",
+            )?;
+            let rows = stmt.query_map(params![vehicle_id.as_bytes().to_vec(), since.timestamp_nanos_opt().unwrap_or(0)], |row| {
+                row.get::<_, Vec<u8>>(0)
+            })?;
+            let mut out = Vec::new();
+            for row in rows {
+                out.push(VehiclePosition::decode(&row?)?);
+            }
+            Ok(out)
+        }
+
+        async fn trust_trend(&self, vehicle_id: VehicleId, window: Duration) -> Result<Vec<TrustScoreUpdate>, StoreError> {
+            let cutoff = Utc::now() - chrono::Duration::from_std(window).unwrap_or(chrono::Duration::zero());
+            let conn = self.conn.lock().expect("sqlite connection mutex poisoned");
+            let mut stmt = conn.prepare(
+                "SELECT payload FROM trust_updates WHERE vehicle_id = ?1 AND timestamp_nanos >= ?2 ORDER BY timestamp_nanos",
+            )?;
+            let rows = stmt.query_map(params![vehicle_id.as_bytes().to_vec(), cutoff.timestamp_nanos_opt().unwrap_or(0)], |row| {
+                row.get::<_, Vec<u8>>(0)
+            })?;
+            let mut out = Vec::new();
+            for row in rows {
+                out.push(TrustScoreUpdate::decode(&row?)?);
+            }
+            Ok(out)
+        }
+
+        async fn compact(&self, horizon: Duration) -> Result<u64, StoreError> {
+            let cutoff = Utc::now() - chrono::Duration::from_std(horizon).unwrap_or(chrono::Duration::zero());
+            let conn = self.conn.lock().expect("sqlite connection mutex poisoned");
+            let cutoff_nanos = cutoff.timestamp_nanos_opt().unwrap_or(0);
+            let removed_positions = conn.execute("DELETE FROM positions WHERE timestamp_nanos < ?1", params![cutoff_nanos])?;
+            let removed_trust = conn.execute("DELETE FROM trust_updates WHERE timestamp_nanos < ?1", params![cutoff_nanos])?;
+            Ok((removed_positions + removed_trust) as u64)
+        }
+    }
+}
+
+use lmdb_store::LmdbHistoryStore;
+use sqlite_store::SqliteHistoryStore;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{EmergencyType, Position};
+
+    fn sample_position(vehicle_id: VehicleId, epoch: u64, sequence: u64, timestamp: DateTime<Utc>) -> VehiclePosition {
+        VehiclePosition {
+            vehicle_id,
+            certificate_id: None,
+            rsu_id: None,
+            position: Position {
+                lat: 1.0,
+                lon: 2.0,
+                alt: None,
+                accuracy_horizontal: None,
+                accuracy_vertical: None,
+                hdop: None,
+                vdop: None,
+                tdop: None,
+                satellites_used: None,
+                satellites_visible: None,
+            },
+            velocity: None,
+            heading: None,
+            speed_accuracy: None,
+            timestamp,
+            sequence,
+            epoch,
+            metadata: None,
+            sensors: Vec::new(),
+            capabilities: Vec::new(),
+            trust: None,
+            security: None,
+            network: None,
+            route_waypoints: Vec::new(),
+            emergency_vehicle: false,
+            emergency_type: EmergencyType::NotEmergency,
+            priority_level: 0,
+            dynamics: None,
+        }
+    }
+
+    fn sample_trust_update(vehicle_id: VehicleId, timestamp: DateTime<Utc>) -> TrustScoreUpdate {
+        TrustScoreUpdate {
+            vehicle_id,
+            score: 0.8,
+            reason: "routine update".to_string(),
+            timestamp,
+            factors: vec!["history".to_string()],
+            previous_score: 0.7,
+            change: 0.1,
+        }
+    }
+
+    async fn run_conformance_suite(store: Box<dyn HistoryStore>) {
+        let vehicle_id = VehicleId::new_v4();
+        let old_time = Utc::now() - chrono::Duration::days(2);
+        let recent_time = Utc::now();
+
+        store.put_position(&sample_position(vehicle_id, 1, 0, old_time)).await.unwrap();
+        store.put_position(&sample_position(vehicle_id, 1, 1, recent_time)).await.unwrap();
+        store.put_trust(&sample_trust_update(vehicle_id, old_time)).await.unwrap();
+        store.put_trust(&sample_trust_update(vehicle_id, recent_time)).await.unwrap();
+
+        let recent = store.recent_positions(vehicle_id, Utc::now() - chrono::Duration::hours(1)).await.unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].sequence, 1);
+
+        let trend = store.trust_trend(vehicle_id, Duration::from_secs(3600)).await.unwrap();
+        assert_eq!(trend.len(), 1);
+
+        let removed = store.compact(Duration::from_secs(86_400)).await.unwrap();
+        assert_eq!(removed, 2, "the day-old position and the day-old trust update should both be dropped by a 24h horizon");
+
+        let after_compact = store.recent_positions(vehicle_id, Utc::now() - chrono::Duration::days(7)).await.unwrap();
+        assert_eq!(after_compact.len(), 1);
+    }
+
+    fn unique_temp_path(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("geovan-store-test-{label}-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn test_lmdb_conformance() {
+        let path = unique_temp_path("lmdb");
+        let store = open(StoreConfig::Lmdb { path, map_size_bytes: 10 * 1024 * 1024 }).await.unwrap();
+        run_conformance_suite(store).await;
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_conformance() {
+        let path = unique_temp_path("sqlite.db");
+        let store = open(StoreConfig::Sqlite { path }).await.unwrap();
+        run_conformance_suite(store).await;
+    }
+}
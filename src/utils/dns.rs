@@ -0,0 +1,200 @@
+//! Pluggable, caching async DNS resolution.
+//!
+//! The system resolver is a bottleneck under large VANET gateway fleets and
+//! leaks query patterns to whatever recursive resolver the host happens to
+//! be configured with. [`CachingResolver`] gives operators control over
+//! upstream servers, transport (plain UDP, DoH, or DoT), and a bounded
+//! TTL-respecting LRU cache, while [`Resolver`] lets networking code depend
+//! on a trait object so tests can inject a stub.
+
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig, ResolverOpts};
+use hickory_resolver::TokioAsyncResolver;
+use lru::LruCache;
+
+use crate::config::{DnsConfig, DnsProtocol};
+use crate::error::{GeoVANError, NetworkError, Result};
+
+/// Resolves a hostname to its IP addresses. Object-safe so call sites can
+/// hold an `Arc<dyn Resolver>` and tests can substitute a stub.
+#[async_trait]
+pub trait Resolver: Send + Sync {
+    async fn resolve(&self, host: &str) -> Result<Vec<IpAddr>>;
+}
+
+struct CacheEntry {
+    addrs: Vec<IpAddr>,
+    expires_at: Instant,
+}
+
+/// Default [`Resolver`], backed by `hickory-resolver` with a bounded
+/// TTL-respecting LRU cache in front of it.
+pub struct CachingResolver {
+    inner: TokioAsyncResolver,
+    cache: Mutex<LruCache<String, CacheEntry>>,
+    max_cache_ttl: Duration,
+}
+
+impl CachingResolver {
+    /// Build a resolver from [`DnsConfig`]: upstream servers, transport,
+    /// and cache sizing all come from config rather than libc defaults.
+    pub fn from_config(config: &DnsConfig) -> Result<Self> {
+        let name_servers = config
+            .upstream_servers
+            .iter()
+            .map(|addr| addr.parse())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| GeoVANError::Network(NetworkError::DnsResolutionFailed(format!(
+                "invalid upstream DNS server address: {e}"
+            ))))?;
+
+        let server_group = match config.protocol {
+            DnsProtocol::Udp => NameServerConfigGroup::from_ips_clear(&name_servers, 53, true),
+            DnsProtocol::Dot => NameServerConfigGroup::from_ips_tls(&name_servers, 853, "dns".to_string(), true),
+            DnsProtocol::Doh => {
+                let doh_url = config.doh_url.as_deref().ok_or_else(|| {
+                    GeoVANError::Network(NetworkError::DnsResolutionFailed(
+                        "dns.protocol = \"doh\" requires dns.doh_url to be set".to_string(),
+                    ))
+                })?;
+                NameServerConfigGroup::from_ips_https(&name_servers, 443, doh_url.to_string(), true)
+            }
+        };
+
+        let resolver_config = ResolverConfig::from_parts(None, Vec::new(), server_group);
+        let inner = TokioAsyncResolver::tokio(resolver_config, ResolverOpts::default());
+
+        Ok(Self {
+            inner,
+            cache: Mutex::new(LruCache::new(
+                std::num::NonZeroUsize::new(config.cache_size.max(1)).expect("max(1) is never zero"),
+            )),
+            max_cache_ttl: config.max_cache_ttl,
+        })
+    }
+
+    fn cached(&self, host: &str) -> Option<Vec<IpAddr>> {
+        let mut cache = self.cache.lock().unwrap();
+        match cache.get(host) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.addrs.clone()),
+            Some(_) => {
+                cache.pop(host);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+#[async_trait]
+impl Resolver for CachingResolver {
+    async fn resolve(&self, host: &str) -> Result<Vec<IpAddr>> {
+        if let Some(addrs) = self.cached(host) {
+            return Ok(addrs);
+        }
+
+        let lookup = self
+            .inner
+            .lookup_ip(host)
+            .await
+            .map_err(|e| GeoVANError::Network(NetworkError::DnsResolutionFailed(format!("{host}: {e}"))))?;
+
+        let ttl = lookup.as_lookup().valid_until().saturating_duration_since(Instant::now()).min(self.max_cache_ttl);
+        let addrs: Vec<IpAddr> = lookup.iter().collect();
+        if addrs.is_empty() {
+            return Err(GeoVANError::Network(NetworkError::DnsResolutionFailed(format!(
+                "{host}: no addresses returned"
+            ))));
+        }
+
+        self.cache
+            .lock()
+            .unwrap()
+            .put(host.to_string(), CacheEntry { addrs: addrs.clone(), expires_at: Instant::now() + ttl });
+
+        Ok(addrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// A stub resolver for tests that don't want to touch the network.
+    struct StubResolver(HashMap<String, Vec<IpAddr>>);
+
+    #[async_trait]
+    impl Resolver for StubResolver {
+        async fn resolve(&self, host: &str) -> Result<Vec<IpAddr>> {
+            self.0
+                .get(host)
+                .cloned()
+                .ok_or_else(|| GeoVANError::Network(NetworkError::DnsResolutionFailed(host.to_string())))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stub_resolver_maps_missing_host_to_dns_error() {
+        let resolver = StubResolver(HashMap::new());
+        let result = resolver.resolve("unknown.geovan.internal").await;
+        assert!(matches!(result, Err(GeoVANError::Network(NetworkError::DnsResolutionFailed(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_stub_resolver_returns_configured_addresses() {
+        let mut hosts = HashMap::new();
+        hosts.insert("gateway.geovan.internal".to_string(), vec!["10.0.0.1".parse().unwrap()]);
+        let resolver = StubResolver(hosts);
+
+        let addrs = resolver.resolve("gateway.geovan.internal").await.unwrap();
+        assert_eq!(addrs, vec!["10.0.0.1".parse::<IpAddr>().unwrap()]);
+    }
+
+    fn test_config() -> DnsConfig {
+        DnsConfig {
+            upstream_servers: vec!["1.1.1.1".to_string()],
+            protocol: DnsProtocol::Udp,
+            doh_url: None,
+            cache_size: 8,
+            max_cache_ttl: Duration::from_secs(300),
+        }
+    }
+
+    #[test]
+    fn test_cached_serves_hit_without_touching_upstream() {
+        let resolver = CachingResolver::from_config(&test_config()).unwrap();
+        let addrs = vec!["10.0.0.1".parse::<IpAddr>().unwrap()];
+        resolver.cache.lock().unwrap().put(
+            "gateway.geovan.internal".to_string(),
+            CacheEntry { addrs: addrs.clone(), expires_at: Instant::now() + Duration::from_secs(60) },
+        );
+
+        assert_eq!(resolver.cached("gateway.geovan.internal"), Some(addrs));
+    }
+
+    #[test]
+    fn test_cached_evicts_expired_entry() {
+        let resolver = CachingResolver::from_config(&test_config()).unwrap();
+        resolver.cache.lock().unwrap().put(
+            "gateway.geovan.internal".to_string(),
+            CacheEntry {
+                addrs: vec!["10.0.0.1".parse().unwrap()],
+                expires_at: Instant::now() - Duration::from_secs(1),
+            },
+        );
+
+        assert_eq!(resolver.cached("gateway.geovan.internal"), None);
+        assert!(resolver.cache.lock().unwrap().get("gateway.geovan.internal").is_none());
+    }
+
+    #[test]
+    fn test_cached_misses_unknown_host() {
+        let resolver = CachingResolver::from_config(&test_config()).unwrap();
+        assert_eq!(resolver.cached("unknown.geovan.internal"), None);
+    }
+}
@@ -0,0 +1,4 @@
+//! Cross-cutting utilities shared across the GeoVAN subsystems.
+
+pub mod dns;
+pub mod resilience;
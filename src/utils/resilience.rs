@@ -0,0 +1,302 @@
+//! Retry executor and circuit breaker for calls to flaky downstreams,
+//! classifying failures via [`GeoVANError::is_recoverable`] /
+//! [`GeoVANError::is_network_error`] so only transient faults are retried
+//! or counted toward tripping a breaker.
+
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::config::RetryConfig;
+use crate::error::{ExternalServiceError, GeoVANError, Result};
+
+/// Whether `err` represents a transient fault worth retrying.
+fn is_retryable(err: &GeoVANError) -> bool {
+    err.is_recoverable() || err.is_network_error()
+}
+
+/// Retry `op` with full-jitter exponential backoff: for attempt `n`
+/// (0-based), sleep a random duration in `[0, min(cap, base * 2^n)]`
+/// between tries. Stops after `policy.max_retries` attempts or as soon as
+/// `op` returns a non-recoverable error. When the error carries a
+/// `Retry-After` hint (see [`GeoVANError::retry_after`]), that delay is
+/// honored instead of the computed backoff.
+pub async fn retry<T, F, Fut>(mut op: F, policy: &RetryConfig) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut last_err = None;
+    let max_retries = policy.max_retries.max(1);
+    for attempt in 0..max_retries {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                if !is_retryable(&err) {
+                    return Err(err);
+                }
+
+                let delay = match err.retry_after() {
+                    Some(hint) => hint,
+                    None => {
+                        let cap = policy.base.mul_f64(2f64.powi(attempt as i32)).min(policy.max);
+                        Duration::from_secs_f64(rand::thread_rng().gen_range(0.0..=cap.as_secs_f64().max(0.0)))
+                    }
+                };
+
+                last_err = Some(err);
+                if attempt + 1 < max_retries {
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+    Err(last_err.expect("at least one attempt is always made"))
+}
+
+/// Circuit breaker state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Closed,
+    Open,
+    HalfProbe,
+}
+
+struct Inner {
+    state: State,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+/// Wraps calls to a single target (e.g. one external service) and trips to
+/// `Open` after `failure_threshold` consecutive recoverable failures,
+/// rejecting further calls until `reset_timeout` has elapsed. After that it
+/// allows a single trial call (`HalfProbe`): success closes the breaker,
+/// failure re-opens it.
+pub struct CircuitBreaker {
+    name: String,
+    failure_threshold: u32,
+    reset_timeout: Duration,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    pub fn new(name: impl Into<String>, failure_threshold: u32, reset_timeout: Duration) -> Self {
+        Self {
+            name: name.into(),
+            failure_threshold,
+            reset_timeout,
+            inner: Mutex::new(Inner { state: State::Closed, consecutive_failures: 0, opened_at: None }),
+        }
+    }
+
+    /// Whether a call should be attempted right now, transitioning
+    /// `Open` -> `HalfProbe` in place once `reset_timeout` has elapsed.
+    fn admit(&self) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state {
+            State::Closed => true,
+            State::HalfProbe => false, // a probe is already in flight
+            State::Open => {
+                let elapsed = inner.opened_at.map(|at| at.elapsed()).unwrap_or(Duration::MAX);
+                if elapsed >= self.reset_timeout {
+                    inner.state = State::HalfProbe;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn record_success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.state = State::Closed;
+        inner.consecutive_failures = 0;
+        inner.opened_at = None;
+    }
+
+    fn record_failure(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state {
+            State::HalfProbe => {
+                inner.state = State::Open;
+                inner.opened_at = Some(Instant::now());
+            }
+            State::Closed => {
+                inner.consecutive_failures += 1;
+                if inner.consecutive_failures >= self.failure_threshold {
+                    inner.state = State::Open;
+                    inner.opened_at = Some(Instant::now());
+                }
+            }
+            State::Open => {}
+        }
+    }
+
+    /// Run `op` if the breaker currently admits calls, otherwise reject
+    /// immediately with `ExternalServiceError::ServiceUnavailable`. Only
+    /// recoverable/network errors count toward tripping the breaker.
+    pub async fn call<T, F, Fut>(&self, op: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        if !self.admit() {
+            return Err(GeoVANError::ExternalService(ExternalServiceError::ServiceUnavailable(format!(
+                "circuit breaker for {} is open",
+                self.name
+            ))));
+        }
+
+        match op().await {
+            Ok(value) => {
+                self.record_success();
+                Ok(value)
+            }
+            Err(err) => {
+                if is_retryable(&err) {
+                    self.record_failure();
+                }
+                Err(err)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::NetworkError;
+
+    fn policy(max_retries: u32) -> RetryConfig {
+        RetryConfig { base: Duration::from_millis(1), max: Duration::from_millis(5), max_retries }
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_on_non_recoverable_error() {
+        let mut attempts = 0;
+        let result: Result<()> = retry(
+            || {
+                attempts += 1;
+                async { Err(GeoVANError::Generic("not transient".to_string())) }
+            },
+            &policy(5),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_makes_one_attempt_when_max_retries_is_zero() {
+        let mut attempts = 0;
+        let result: Result<()> = retry(
+            || {
+                attempts += 1;
+                async { Err(GeoVANError::Network(NetworkError::ConnectionTimeout("slow".to_string()))) }
+            },
+            &policy(0),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_succeeds_after_transient_failures() {
+        let mut attempts = 0;
+        let result = retry(
+            || {
+                attempts += 1;
+                let succeed = attempts >= 3;
+                async move {
+                    if succeed {
+                        Ok(())
+                    } else {
+                        Err(GeoVANError::Network(NetworkError::ConnectionTimeout("slow".to_string())))
+                    }
+                }
+            },
+            &policy(5),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_honors_retry_after_hint() {
+        let mut attempts = 0;
+        let started = Instant::now();
+        let result: Result<()> = retry(
+            || {
+                attempts += 1;
+                async move {
+                    if attempts >= 2 {
+                        Ok(())
+                    } else {
+                        Err(GeoVANError::RateLimit {
+                            message: "slow down".to_string(),
+                            retry_after: Some(Duration::from_millis(20)),
+                        })
+                    }
+                }
+            },
+            &policy(5),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_trips_after_threshold() {
+        let breaker = CircuitBreaker::new("downstream", 2, Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let _: Result<()> = breaker
+                .call(|| async { Err(GeoVANError::Network(NetworkError::ConnectionFailed("down".to_string()))) })
+                .await;
+        }
+
+        let result: Result<()> = breaker.call(|| async { Ok(()) }).await;
+        assert!(matches!(
+            result,
+            Err(GeoVANError::ExternalService(ExternalServiceError::ServiceUnavailable(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_half_probe_closes_on_success() {
+        let breaker = CircuitBreaker::new("downstream", 1, Duration::from_millis(5));
+
+        let _: Result<()> = breaker
+            .call(|| async { Err(GeoVANError::Network(NetworkError::ConnectionFailed("down".to_string()))) })
+            .await;
+        assert!(matches!(
+            breaker.call(|| async { Ok(()) }).await,
+            Err(GeoVANError::ExternalService(_))
+        ));
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let result: Result<()> = breaker.call(|| async { Ok(()) }).await;
+        assert!(result.is_ok());
+
+        // Breaker is closed again with its failure count reset; with a
+        // threshold of 1, a single further failure trips it open again.
+        let _: Result<()> = breaker
+            .call(|| async { Err(GeoVANError::Network(NetworkError::ConnectionFailed("down again".to_string()))) })
+            .await;
+        assert!(matches!(
+            breaker.call(|| async { Ok(()) }).await,
+            Err(GeoVANError::ExternalService(_))
+        ));
+    }
+}